@@ -0,0 +1,125 @@
+use gitee_rs::{Branch, GiteeClient, ProtectionRule};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::BranchCommands;
+
+pub async fn handle_branches(client: &GiteeClient, cmd: &BranchCommands) -> Result<()> {
+    match cmd {
+        BranchCommands::List { owner, repo } => {
+            println!("Fetching branches for {}/{}...", owner, repo);
+            match client.list_branches(owner, repo).await {
+                Ok(branches) => {
+                    if branches.is_empty() {
+                        println!("No branches found.");
+                    } else {
+                        for branch in branches {
+                            print_branch(&branch);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching branches", &e),
+            }
+        }
+        BranchCommands::Get { owner, repo, branch } => {
+            println!("Fetching branch '{}' in {}/{}...", branch, owner, repo);
+            match client.get_branch(owner, repo, branch).await {
+                Ok(branch) => {
+                    print_branch(&branch);
+                    if let Some(detail) = &branch.commit.commit {
+                        if let Some(author) = &detail.author {
+                            println!("  Author: {} <{}> at {}",
+                                author.name.as_deref().unwrap_or("unknown"),
+                                author.email.as_deref().unwrap_or(""),
+                                author.date.as_deref().unwrap_or(""));
+                        }
+                        if let Some(message) = &detail.message {
+                            println!("  Message: {}", message.lines().next().unwrap_or(""));
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching branch", &e),
+            }
+        }
+        BranchCommands::Create { owner, repo, branch, refs } => {
+            println!("Creating branch '{}' from '{}' in {}/{}...", branch, refs, owner, repo);
+            match client.create_branch(owner, repo, branch, refs).await {
+                Ok(branch) => {
+                    println!("Successfully created branch:");
+                    print_branch(&branch);
+                }
+                Err(e) => report_error("Error creating branch", &e),
+            }
+        }
+        BranchCommands::Delete { owner, repo, branch } => {
+            println!("Deleting branch '{}' in {}/{}...", branch, owner, repo);
+            match client.delete_branch(owner, repo, branch).await {
+                Ok(_) => println!("Successfully deleted branch."),
+                Err(e) => report_error("Error deleting branch", &e),
+            }
+        }
+        BranchCommands::Protect { owner, repo, branch } => {
+            println!("Protecting branch '{}' in {}/{}...", branch, owner, repo);
+            match client.protect_branch(owner, repo, branch).await {
+                Ok(branch) => {
+                    println!("Successfully protected branch:");
+                    print_branch(&branch);
+                }
+                Err(e) => report_error("Error protecting branch", &e),
+            }
+        }
+        BranchCommands::Unprotect { owner, repo, branch } => {
+            println!("Removing protection from branch '{}' in {}/{}...", branch, owner, repo);
+            match client.unprotect_branch(owner, repo, branch).await {
+                Ok(_) => println!("Successfully removed branch protection."),
+                Err(e) => report_error("Error removing branch protection", &e),
+            }
+        }
+        BranchCommands::CreateRule { owner, repo, wildcard, pusher, merger } => {
+            println!("Creating protection rule '{}' in {}/{}...", wildcard, owner, repo);
+            match client.create_protection_rule(owner, repo, wildcard, pusher.as_deref(), merger.as_deref()).await {
+                Ok(rule) => {
+                    println!("Successfully created protection rule:");
+                    print_protection_rule(&rule);
+                }
+                Err(e) => report_error("Error creating protection rule", &e),
+            }
+        }
+        BranchCommands::UpdateRule { owner, repo, wildcard, new_wildcard, pusher, merger } => {
+            println!("Updating protection rule '{}' in {}/{}...", wildcard, owner, repo);
+            match client.update_protection_rule(owner, repo, wildcard, new_wildcard.as_deref(), pusher.as_deref(), merger.as_deref()).await {
+                Ok(rule) => {
+                    println!("Successfully updated protection rule:");
+                    print_protection_rule(&rule);
+                }
+                Err(e) => report_error("Error updating protection rule", &e),
+            }
+        }
+        BranchCommands::DeleteRule { owner, repo, wildcard } => {
+            println!("Deleting protection rule '{}' in {}/{}...", wildcard, owner, repo);
+            match client.delete_protection_rule(owner, repo, wildcard).await {
+                Ok(_) => println!("Successfully deleted protection rule."),
+                Err(e) => report_error("Error deleting protection rule", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn print_branch(branch: &Branch) {
+    let protected = if branch.protected { " [protected]" } else { "" };
+    println!("{}{} -> {}", branch.name, protected, branch.commit.sha);
+}
+
+fn print_protection_rule(rule: &ProtectionRule) {
+    println!("Wildcard: {}", rule.wildcard);
+    println!("  Pushers: {}", member_names(&rule.pushers));
+    println!("  Mergers: {}", member_names(&rule.mergers));
+}
+
+fn member_names(members: &[serde_json::Value]) -> String {
+    let names: Vec<&str> = members
+        .iter()
+        .filter_map(|m| m.get("login").or_else(|| m.get("name")).and_then(|v| v.as_str()).or_else(|| m.as_str()))
+        .collect();
+    if names.is_empty() { "-".to_string() } else { names.join(", ") }
+}
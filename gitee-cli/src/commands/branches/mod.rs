@@ -0,0 +1,105 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum BranchCommands {
+    /// List branches of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+    },
+    /// Get a branch and its head commit
+    Get {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch name
+        branch: String,
+    },
+    /// Create a new branch
+    Create {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Name of the new branch
+        branch: String,
+        /// Branch, tag or commit to start from
+        #[arg(long = "from")]
+        refs: String,
+    },
+    /// Delete a branch
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch name
+        branch: String,
+    },
+    /// Protect a branch
+    Protect {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch name
+        branch: String,
+    },
+    /// Remove protection from a branch
+    Unprotect {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch name
+        branch: String,
+    },
+    /// Create a protection rule for branches matching a wildcard
+    CreateRule {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch wildcard (e.g. release/*)
+        wildcard: String,
+        /// Who may push: admin, none or comma-separated usernames
+        #[arg(long)]
+        pusher: Option<String>,
+        /// Who may merge: admin, none or comma-separated usernames
+        #[arg(long)]
+        merger: Option<String>,
+    },
+    /// Update a wildcard protection rule
+    UpdateRule {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Current branch wildcard
+        wildcard: String,
+        /// New branch wildcard (optional)
+        #[arg(long)]
+        new_wildcard: Option<String>,
+        /// Who may push: admin, none or comma-separated usernames (unchanged when omitted)
+        #[arg(long)]
+        pusher: Option<String>,
+        /// Who may merge: admin, none or comma-separated usernames (unchanged when omitted)
+        #[arg(long)]
+        merger: Option<String>,
+    },
+    /// Delete a wildcard protection rule
+    DeleteRule {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch wildcard
+        wildcard: String,
+    },
+}
@@ -0,0 +1,64 @@
+use gitee_rs::{Collaborator, GiteeClient, Permission};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::CollaboratorCommands;
+
+pub async fn handle_collaborators(client: &GiteeClient, cmd: &CollaboratorCommands) -> Result<()> {
+    match cmd {
+        CollaboratorCommands::List { owner, repo } => {
+            println!("Fetching collaborators for {}/{}...", owner, repo);
+            match client.list_collaborators(owner, repo).await {
+                Ok(collaborators) => {
+                    if collaborators.is_empty() {
+                        println!("No collaborators found.");
+                    } else {
+                        for collaborator in collaborators {
+                            print_collaborator(&collaborator);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching collaborators", &e),
+            }
+        }
+        CollaboratorCommands::Check { owner, repo, username } => {
+            match client.is_collaborator(owner, repo, username).await {
+                Ok(true) => println!("{} is a collaborator on {}/{}.", username, owner, repo),
+                Ok(false) => println!("{} is not a collaborator on {}/{}.", username, owner, repo),
+                Err(e) => report_error("Error checking collaborator", &e),
+            }
+        }
+        CollaboratorCommands::Add { owner, repo, username, permission } => {
+            let permission = Permission::parse(permission).unwrap_or(Permission::Push);
+            println!("Adding {} to {}/{} with {} permission...", username, owner, repo, permission.as_str());
+            match client.add_collaborator(owner, repo, username, permission).await {
+                Ok(collaborator) => {
+                    println!("Successfully added collaborator:");
+                    print_collaborator(&collaborator);
+                }
+                Err(e) => report_error("Error adding collaborator", &e),
+            }
+        }
+        CollaboratorCommands::Remove { owner, repo, username } => {
+            println!("Removing {} from {}/{}...", username, owner, repo);
+            match client.remove_collaborator(owner, repo, username).await {
+                Ok(_) => println!("Successfully removed collaborator."),
+                Err(e) => report_error("Error removing collaborator", &e),
+            }
+        }
+        CollaboratorCommands::Permission { owner, repo, username } => {
+            match client.get_collaborator_permission(owner, repo, username).await {
+                Ok(permission) => println!("{} has {} permission on {}/{}.", username, permission.permission, owner, repo),
+                Err(e) => report_error("Error fetching permission", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_collaborator(collaborator: &Collaborator) {
+    let permission = collaborator.permission().map(Permission::as_str).unwrap_or("none");
+    match &collaborator.name {
+        Some(name) => println!("{} ({}) [{}]", collaborator.login, name, permission),
+        None => println!("{} [{}]", collaborator.login, permission),
+    }
+}
@@ -0,0 +1,54 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum CollaboratorCommands {
+    /// List collaborators of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+    },
+    /// Check whether a user is a collaborator
+    Check {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Username to check
+        username: String,
+    },
+    /// Add a collaborator or change their permission
+    Add {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Username to add
+        username: String,
+        /// Permission to grant
+        #[arg(long, default_value = "push", value_parser = ["pull", "push", "admin"])]
+        permission: String,
+    },
+    /// Remove a collaborator
+    Remove {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Username to remove
+        username: String,
+    },
+    /// Show a user's permission on a repository
+    Permission {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Username to look up
+        username: String,
+    },
+}
@@ -0,0 +1,82 @@
+use gitee_rs::{commits::CommitListOptions, Commit, FileDiff, GiteeClient};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::CommitCommands;
+
+pub async fn handle_commits(client: &GiteeClient, cmd: &CommitCommands) -> Result<()> {
+    match cmd {
+        CommitCommands::List { owner, repo, sha, path, author, since, until, per_page } => {
+            println!("Fetching commits for {}/{}...", owner, repo);
+            let options = CommitListOptions {
+                sha: sha.clone(),
+                path: path.clone(),
+                author: author.clone(),
+                since: since.clone(),
+                until: until.clone(),
+                per_page: *per_page,
+                ..Default::default()
+            };
+            match client.list_commits(owner, repo, Some(options)).await {
+                Ok(commits) => {
+                    if commits.is_empty() {
+                        println!("No commits found.");
+                    } else {
+                        for commit in commits {
+                            print_commit(&commit);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching commits", &e),
+            }
+        }
+        CommitCommands::Get { owner, repo, sha } => {
+            println!("Fetching commit {} in {}/{}...", sha, owner, repo);
+            match client.get_commit(owner, repo, sha).await {
+                Ok(commit) => {
+                    print_commit(&commit);
+                    if let Some(stats) = &commit.stats {
+                        println!("  Stats: +{} -{} ({} total)", stats.additions, stats.deletions, stats.total);
+                    }
+                    print_files(&commit.files);
+                }
+                Err(e) => report_error("Error fetching commit", &e),
+            }
+        }
+        CommitCommands::Compare { owner, repo, base, head } => {
+            println!("Comparing {}...{} in {}/{}...", base, head, owner, repo);
+            match client.compare_commits(owner, repo, base, head).await {
+                Ok(compare) => {
+                    if let Some(merge_base) = &compare.merge_base_commit {
+                        println!("Merge base: {}", short_sha(&merge_base.sha));
+                    }
+                    println!("{} commit(s), {} file(s) changed", compare.commits.len(), compare.files.len());
+                    for commit in &compare.commits {
+                        print_commit(commit);
+                    }
+                    print_files(&compare.files);
+                }
+                Err(e) => report_error("Error comparing commits", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn print_commit(commit: &Commit) {
+    let author = commit.commit.author.as_ref();
+    println!("{} {} ({}, {})",
+        short_sha(&commit.sha),
+        commit.summary(),
+        author.and_then(|a| a.name.as_deref()).unwrap_or("unknown"),
+        author.and_then(|a| a.date.as_deref()).unwrap_or(""));
+}
+
+fn print_files(files: &[FileDiff]) {
+    for file in files {
+        println!("  {} {} (+{} -{})", file.status, file.filename, file.additions, file.deletions);
+    }
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
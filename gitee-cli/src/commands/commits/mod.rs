@@ -0,0 +1,53 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum CommitCommands {
+    /// List commits of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch name or commit sha to start from (defaults to the default branch)
+        #[arg(long)]
+        sha: Option<String>,
+        /// Only commits touching this file path
+        #[arg(long)]
+        path: Option<String>,
+        /// Only commits by this author
+        #[arg(long)]
+        author: Option<String>,
+        /// Only commits after this date (ISO 8601)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only commits before this date (ISO 8601)
+        #[arg(long)]
+        until: Option<String>,
+        /// Number of commits per page
+        #[arg(long)]
+        per_page: Option<i32>,
+    },
+    /// Get a commit with its stats and changed files
+    Get {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Commit sha, branch or tag
+        sha: String,
+    },
+    /// Compare two branches, tags or commits
+    Compare {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Base ref
+        base: String,
+        /// Head ref
+        head: String,
+    },
+}
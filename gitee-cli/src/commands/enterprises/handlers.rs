@@ -0,0 +1,149 @@
+use gitee_rs::{enterprises::{EnterpriseIssueListOptions, EnterpriseMemberListOptions, EnterprisePullListOptions, EnterpriseRepoListOptions}, EnterpriseIssueState, GiteeClient, Issue, PullRequest};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::EnterpriseCommands;
+
+pub async fn handle_enterprises(client: &GiteeClient, cmd: &EnterpriseCommands) -> Result<()> {
+    match cmd {
+        EnterpriseCommands::Info { enterprise } => {
+            match client.get_enterprise(enterprise).await {
+                Ok(ent) => {
+                    println!("Enterprise: {} ({})", ent.name, ent.path);
+                    if let Some(url) = &ent.url {
+                        println!("URL: {}", url);
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise", &e),
+            }
+        }
+        EnterpriseCommands::Members { enterprise, role } => {
+            println!("Fetching members of enterprise {}...", enterprise);
+            let options = EnterpriseMemberListOptions { role: role.clone(), ..Default::default() };
+            match client.paginate_enterprise_members(enterprise, Some(options)).collect_all(None).await {
+                Ok(members) => {
+                    if members.is_empty() {
+                        println!("No members found.");
+                    } else {
+                        for member in members {
+                            let remark = member.remark.as_deref().filter(|r| !r.is_empty()).unwrap_or("");
+                            println!("{} {} [{}]{}", member.user.login, remark, member.role,
+                                if member.outsourced { " (outsourced)" } else { "" });
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise members", &e),
+            }
+        }
+        EnterpriseCommands::Repos { enterprise, repo_type, search, all } => {
+            println!("Fetching repositories of enterprise {}...", enterprise);
+            let options = EnterpriseRepoListOptions {
+                repo_type: repo_type.clone(),
+                search: search.clone(),
+                ..Default::default()
+            };
+            let result = if *all {
+                client.paginate_enterprise_repos(enterprise, Some(options)).collect_all(None).await
+            } else {
+                client.list_enterprise_repos(enterprise, Some(options)).await
+            };
+            match result {
+                Ok(repos) => {
+                    if repos.is_empty() {
+                        println!("No repositories found.");
+                    } else {
+                        for repo in repos {
+                            println!("{}{} ({})", repo.full_name, if repo.private { " [private]" } else { "" }, repo.html_url);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise repositories", &e),
+            }
+        }
+        EnterpriseCommands::Issues { enterprise, state, issue_type, labels, assignee, all } => {
+            println!("Fetching issues of enterprise {}...", enterprise);
+            let options = EnterpriseIssueListOptions {
+                state: state.as_deref().and_then(EnterpriseIssueState::parse),
+                labels: labels.clone(),
+                assignee: assignee.clone(),
+                ..Default::default()
+            };
+            let result = if *all {
+                client.paginate_enterprise_issues(enterprise, Some(options)).collect_all(None).await
+            } else {
+                client.list_enterprise_issues(enterprise, Some(options)).await
+            };
+            match result {
+                Ok(issues) => {
+                    // The enterprise endpoint cannot filter by type, so do it here
+                    let issues: Vec<Issue> = issues
+                        .into_iter()
+                        .filter(|i| issue_type.is_none() || i.issue_type == *issue_type)
+                        .collect();
+                    if issues.is_empty() {
+                        println!("No issues found.");
+                    } else {
+                        for issue in &issues {
+                            print_enterprise_issue(issue);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise issues", &e),
+            }
+        }
+        EnterpriseCommands::Pulls { enterprise, state, repo, all } => {
+            println!("Fetching pull requests of enterprise {}...", enterprise);
+            let options = EnterprisePullListOptions {
+                state: state.clone(),
+                repo: repo.clone(),
+                ..Default::default()
+            };
+            let result = if *all {
+                client.paginate_enterprise_pulls(enterprise, Some(options)).collect_all(None).await
+            } else {
+                client.list_enterprise_pulls(enterprise, Some(options)).await
+            };
+            match result {
+                Ok(pulls) => {
+                    if pulls.is_empty() {
+                        println!("No pull requests found.");
+                    } else {
+                        for pr in &pulls {
+                            print_enterprise_pull(pr);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise pull requests", &e),
+            }
+        }
+        EnterpriseCommands::Labels { enterprise } => {
+            match client.list_enterprise_labels(enterprise).await {
+                Ok(labels) => {
+                    if labels.is_empty() {
+                        println!("No labels found.");
+                    } else {
+                        for label in labels {
+                            println!("{} [#{}]", label.name, label.color);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching enterprise labels", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_enterprise_issue(issue: &Issue) {
+    let repo = issue.repository.as_ref().map(|r| r.full_name.as_str()).unwrap_or("?");
+    let state = match &issue.issue_state {
+        Some(workflow) => format!("{}/{}", issue.state, workflow),
+        None => issue.state.clone(),
+    };
+    let issue_type = issue.issue_type.as_deref().map(|t| format!(" ({})", t)).unwrap_or_default();
+    println!("{}#{} [{}]{} {}", repo, issue.number, state, issue_type, issue.title);
+}
+
+fn print_enterprise_pull(pr: &PullRequest) {
+    let repo = pr.base.repo.as_ref().map(|r| r.full_name.as_str()).unwrap_or("?");
+    println!("{}!{} [{}] {} ({} -> {})", repo, pr.number, pr.state, pr.title, pr.head.ref_name, pr.base.ref_name);
+}
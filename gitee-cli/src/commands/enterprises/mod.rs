@@ -0,0 +1,74 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum EnterpriseCommands {
+    /// Get enterprise information
+    Info {
+        /// Enterprise path
+        enterprise: String,
+    },
+    /// List enterprise members
+    Members {
+        /// Enterprise path
+        enterprise: String,
+        /// Only list members with this role
+        #[arg(long, value_parser = ["all", "admin", "member"])]
+        role: Option<String>,
+    },
+    /// List enterprise repositories
+    Repos {
+        /// Enterprise path
+        enterprise: String,
+        /// Repository visibility filter
+        #[arg(long = "type", value_parser = ["all", "public", "internal", "private"])]
+        repo_type: Option<String>,
+        /// Only repositories whose name matches this keyword
+        #[arg(long)]
+        search: Option<String>,
+        /// Fetch every page instead of only the first
+        #[arg(long)]
+        all: bool,
+    },
+    /// List issues across all enterprise repositories
+    Issues {
+        /// Enterprise path
+        enterprise: String,
+        /// Issue state
+        #[arg(long, value_parser = ["open", "progressing", "closed", "rejected", "all"])]
+        state: Option<String>,
+        /// Only issues of this type, e.g. 缺陷 or 需求
+        #[arg(long = "type")]
+        issue_type: Option<String>,
+        /// Comma separated label names
+        #[arg(long)]
+        labels: Option<String>,
+        /// Only issues assigned to this user
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Fetch every page instead of only the first
+        #[arg(long)]
+        all: bool,
+    },
+    /// List pull requests across all enterprise repositories
+    Pulls {
+        /// Enterprise path
+        enterprise: String,
+        /// Pull request state
+        #[arg(long, value_parser = ["open", "closed", "merged", "all"])]
+        state: Option<String>,
+        /// Only pull requests of this repository path
+        #[arg(long)]
+        repo: Option<String>,
+        /// Fetch every page instead of only the first
+        #[arg(long)]
+        all: bool,
+    },
+    /// List enterprise labels
+    Labels {
+        /// Enterprise path
+        enterprise: String,
+    },
+}
@@ -1,6 +1,7 @@
-use gitee_rs::GiteeClient;
+use gitee_rs::{files::{CommitIdentity, FileCommit, FileWriteOptions}, GiteeClient};
 use anyhow::Result;
-use super::FileCommands;
+use crate::errors::report_error;
+use super::{CommitIdentityArgs, FileCommands};
 
 pub async fn handle_files(client: &GiteeClient, cmd: &FileCommands) -> Result<()> {
     match cmd {
@@ -11,15 +12,79 @@ pub async fn handle_files(client: &GiteeClient, cmd: &FileCommands) -> Result<()
                     println!("File: {} (size: {})", file_content.name, file_content.size.unwrap_or(0));
                     println!("Path: {}", file_content.path);
                     println!("Type: {}", file_content.file_type);
-                    if let Some(content) = &file_content.content {
-                        println!("Content preview:");
-                        println!("{}", content.chars().take(500).collect::<String>());
-                        if content.len() > 500 {
-                            println!("... (truncated)");
+                    match file_content.decoded_text() {
+                        Ok(content) => {
+                            println!("Content preview:");
+                            println!("{}", content.chars().take(500).collect::<String>());
+                            if content.chars().count() > 500 {
+                                println!("... (truncated)");
+                            }
                         }
+                        Err(e) => println!("Content not shown: {} (use `gitee files raw` to download)", e),
                     }
                 }
-                Err(e) => eprintln!("Error fetching file content: {}", e),
+                Err(e) => report_error("Error fetching file content", &e),
+            }
+        }
+        FileCommands::Raw { owner, repo, path, r#ref, output } => {
+            match output {
+                Some(output) => {
+                    println!("Downloading {}/{}/{} to {}...", owner, repo, path, output);
+                    let mut file = tokio::fs::File::create(output).await?;
+                    match client.download_raw_file(owner, repo, path, r#ref.as_deref(), &mut file).await {
+                        Ok(bytes) => println!("Successfully downloaded {} bytes.", bytes),
+                        Err(e) => {
+                            drop(file);
+                            let _ = tokio::fs::remove_file(output).await;
+                            report_error("Error downloading file", &e);
+                        }
+                    }
+                }
+                None => {
+                    let mut stdout = tokio::io::stdout();
+                    if let Err(e) = client.download_raw_file(owner, repo, path, r#ref.as_deref(), &mut stdout).await {
+                        report_error("Error downloading file", &e);
+                    }
+                }
+            }
+        }
+        FileCommands::Readme { owner, repo, r#ref } => {
+            match client.get_readme_text(owner, repo, r#ref.as_deref()).await {
+                Ok(readme) => {
+                    println!("README: {} ({})", readme.path, readme.html_url);
+                    println!();
+                    println!("{}", readme.text);
+                }
+                Err(e) => report_error("Error fetching README", &e),
+            }
+        }
+        FileCommands::Tree { owner, repo, r#ref, shallow } => {
+            let r#ref = match r#ref {
+                Some(r#ref) => r#ref.clone(),
+                None => match client.get_repo(owner, repo).await {
+                    Ok(r) => r.default_branch.unwrap_or_else(|| "master".to_string()),
+                    Err(e) => {
+                        report_error("Error fetching repository default branch", &e);
+                        return Ok(());
+                    }
+                },
+            };
+            println!("Fetching tree of {} in {}/{}...", r#ref, owner, repo);
+            match client.get_tree(owner, repo, &r#ref, !shallow).await {
+                Ok(tree) => {
+                    for entry in &tree.tree {
+                        match entry.size {
+                            Some(size) if entry.is_blob() => println!("{} {} ({} bytes)", entry.entry_type, entry.path, size),
+                            _ => println!("{} {}", entry.entry_type, entry.path),
+                        }
+                    }
+                    let files = tree.tree.iter().filter(|e| e.is_blob()).count();
+                    println!("{} entries, {} files", tree.tree.len(), files);
+                    if tree.truncated {
+                        println!("Warning: the tree was truncated by the server.");
+                    }
+                }
+                Err(e) => report_error("Error fetching tree", &e),
             }
         }
         FileCommands::List { owner, repo, path } => {
@@ -35,7 +100,7 @@ pub async fn handle_files(client: &GiteeClient, cmd: &FileCommands) -> Result<()
                         }
                     }
                 }
-                Err(e) => eprintln!("Error listing files: {}", e),
+                Err(e) => report_error("Error listing files", &e),
             }
         }
         FileCommands::Search { query, owner } => {
@@ -51,9 +116,86 @@ pub async fn handle_files(client: &GiteeClient, cmd: &FileCommands) -> Result<()
                         }
                     }
                 }
-                Err(e) => eprintln!("Error searching files: {}", e),
+                Err(e) => report_error("Error searching files", &e),
+            }
+        }
+        FileCommands::Create { owner, repo, path, message, content, from_file, branch, identity } => {
+            let content = read_content(content, from_file)?;
+            let options = write_options(branch, identity);
+            println!("Creating file {} in {}/{}...", path, owner, repo);
+            match client.create_file(owner, repo, path, content, message, Some(options)).await {
+                Ok(result) => print_file_commit("Successfully created file.", &result),
+                Err(e) => report_error("Error creating file", &e),
+            }
+        }
+        FileCommands::Update { owner, repo, path, message, content, from_file, sha, branch, identity } => {
+            let content = read_content(content, from_file)?;
+            let Some(sha) = resolve_sha(client, owner, repo, path, sha, branch).await else {
+                return Ok(());
+            };
+            let options = write_options(branch, identity);
+            println!("Updating file {} in {}/{}...", path, owner, repo);
+            match client.update_file(owner, repo, path, content, &sha, message, Some(options)).await {
+                Ok(result) => print_file_commit("Successfully updated file.", &result),
+                Err(e) => report_error("Error updating file", &e),
+            }
+        }
+        FileCommands::Delete { owner, repo, path, message, sha, branch, identity } => {
+            let Some(sha) = resolve_sha(client, owner, repo, path, sha, branch).await else {
+                return Ok(());
+            };
+            let options = write_options(branch, identity);
+            println!("Deleting file {} in {}/{}...", path, owner, repo);
+            match client.delete_file(owner, repo, path, &sha, message, Some(options)).await {
+                Ok(result) => print_file_commit("Successfully deleted file.", &result),
+                Err(e) => report_error("Error deleting file", &e),
             }
         }
     }
     Ok(())
 }
+
+fn read_content(content: &Option<String>, from_file: &Option<String>) -> Result<Vec<u8>> {
+    match (content, from_file) {
+        (Some(content), _) => Ok(content.clone().into_bytes()),
+        (None, Some(file)) => Ok(std::fs::read(file)?),
+        (None, None) => anyhow::bail!("Either --content or --from-file is required"),
+    }
+}
+
+fn write_options(branch: &Option<String>, identity: &CommitIdentityArgs) -> FileWriteOptions {
+    FileWriteOptions {
+        branch: branch.clone(),
+        author: commit_identity(&identity.author_name, &identity.author_email),
+        committer: commit_identity(&identity.committer_name, &identity.committer_email),
+    }
+}
+
+fn commit_identity(name: &Option<String>, email: &Option<String>) -> Option<CommitIdentity> {
+    match (name, email) {
+        (Some(name), Some(email)) => Some(CommitIdentity { name: name.clone(), email: email.clone() }),
+        _ => None,
+    }
+}
+
+/// Use the given blob sha, or look up the current one on the target branch
+async fn resolve_sha(client: &GiteeClient, owner: &str, repo: &str, path: &str, sha: &Option<String>, branch: &Option<String>) -> Option<String> {
+    if let Some(sha) = sha {
+        return Some(sha.clone());
+    }
+    match client.get_file_content(owner, repo, path, branch.as_deref()).await {
+        Ok(file) => Some(file.sha),
+        Err(e) => {
+            report_error("Error fetching current file sha", &e);
+            None
+        }
+    }
+}
+
+fn print_file_commit(header: &str, result: &FileCommit) {
+    println!("{}", header);
+    println!("  Commit: {}", result.commit.sha);
+    if let Some(content) = &result.content {
+        println!("  File: {} (sha: {})", content.path, content.sha);
+    }
+}
@@ -1,4 +1,4 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 
 mod handlers;
 pub use handlers::*;
@@ -14,6 +14,44 @@ pub enum FileCommands {
         /// Path to the file
         path: String,
     },
+    /// Download the raw content of a file (suitable for binary files)
+    Raw {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Path to the file
+        path: String,
+        /// Branch, tag or commit (defaults to the default branch)
+        #[arg(long = "ref")]
+        r#ref: Option<String>,
+        /// Write to this local file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Print the README of a repository
+    Readme {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch, tag or commit (defaults to the default branch)
+        #[arg(long = "ref")]
+        r#ref: Option<String>,
+    },
+    /// Print the full file tree of a branch, tag or commit
+    Tree {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch, tag or commit (defaults to the default branch)
+        #[arg(long = "ref")]
+        r#ref: Option<String>,
+        /// Only list the top level
+        #[arg(long)]
+        shallow: bool,
+    },
     /// List repository files
     List {
         /// Owner of the repository
@@ -32,4 +70,90 @@ pub enum FileCommands {
         #[arg(long)]
         owner: Option<String>,
     },
+    /// Create a file and commit it
+    Create {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Path of the file in the repository
+        path: String,
+        /// Commit message
+        #[arg(short, long)]
+        message: String,
+        /// File content
+        #[arg(long, conflicts_with = "from_file", required_unless_present = "from_file")]
+        content: Option<String>,
+        /// Read the file content from a local file
+        #[arg(long)]
+        from_file: Option<String>,
+        /// Branch to commit to (defaults to the default branch)
+        #[arg(long)]
+        branch: Option<String>,
+        #[command(flatten)]
+        identity: CommitIdentityArgs,
+    },
+    /// Update a file and commit the change
+    Update {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Path of the file in the repository
+        path: String,
+        /// Commit message
+        #[arg(short, long)]
+        message: String,
+        /// New file content
+        #[arg(long, conflicts_with = "from_file", required_unless_present = "from_file")]
+        content: Option<String>,
+        /// Read the new file content from a local file
+        #[arg(long)]
+        from_file: Option<String>,
+        /// Blob sha of the version being replaced (fetched when omitted)
+        #[arg(long)]
+        sha: Option<String>,
+        /// Branch to commit to (defaults to the default branch)
+        #[arg(long)]
+        branch: Option<String>,
+        #[command(flatten)]
+        identity: CommitIdentityArgs,
+    },
+    /// Delete a file and commit the removal
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Path of the file in the repository
+        path: String,
+        /// Commit message
+        #[arg(short, long)]
+        message: String,
+        /// Blob sha of the file being deleted (fetched when omitted)
+        #[arg(long)]
+        sha: Option<String>,
+        /// Branch to commit to (defaults to the default branch)
+        #[arg(long)]
+        branch: Option<String>,
+        #[command(flatten)]
+        identity: CommitIdentityArgs,
+    },
+}
+
+/// Who the commit is attributed to; Gitee uses the token owner for anything left unset
+#[derive(Args)]
+pub struct CommitIdentityArgs {
+    /// Commit author name
+    #[arg(long, requires = "author_email")]
+    pub author_name: Option<String>,
+    /// Commit author email
+    #[arg(long, requires = "author_name")]
+    pub author_email: Option<String>,
+    /// Committer name
+    #[arg(long, requires = "committer_email")]
+    pub committer_name: Option<String>,
+    /// Committer email
+    #[arg(long, requires = "committer_name")]
+    pub committer_email: Option<String>,
 }
@@ -0,0 +1,92 @@
+use gitee_rs::{hooks::{HookAuth, HookOptions}, GiteeClient, Hook, HookEvent};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::{HookCommands, HookSettings};
+
+pub async fn handle_hooks(client: &GiteeClient, cmd: &HookCommands) -> Result<()> {
+    match cmd {
+        HookCommands::List { owner, repo } => {
+            println!("Fetching webhooks for {}/{}...", owner, repo);
+            match client.list_hooks(owner, repo).await {
+                Ok(hooks) => {
+                    if hooks.is_empty() {
+                        println!("No webhooks found.");
+                    } else {
+                        for hook in hooks {
+                            print_hook(&hook);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching webhooks", &e),
+            }
+        }
+        HookCommands::Get { owner, repo, id } => {
+            match client.get_hook(owner, repo, id).await {
+                Ok(hook) => print_hook(&hook),
+                Err(e) => report_error("Error fetching webhook", &e),
+            }
+        }
+        HookCommands::Create { owner, repo, url, settings } => {
+            let options = hook_options(settings);
+            println!("Creating webhook for {}/{}...", owner, repo);
+            match client.create_hook(owner, repo, url, Some(options)).await {
+                Ok(hook) => {
+                    println!("Successfully created webhook:");
+                    print_hook(&hook);
+                }
+                Err(e) => report_error("Error creating webhook", &e),
+            }
+        }
+        HookCommands::Update { owner, repo, id, url, settings } => {
+            let options = hook_options(settings);
+            println!("Updating webhook {} in {}/{}...", id, owner, repo);
+            match client.update_hook(owner, repo, id, url.as_deref(), Some(options)).await {
+                Ok(hook) => {
+                    println!("Successfully updated webhook:");
+                    print_hook(&hook);
+                }
+                Err(e) => report_error("Error updating webhook", &e),
+            }
+        }
+        HookCommands::Delete { owner, repo, id } => {
+            println!("Deleting webhook {} in {}/{}...", id, owner, repo);
+            match client.delete_hook(owner, repo, id).await {
+                Ok(_) => println!("Successfully deleted webhook."),
+                Err(e) => report_error("Error deleting webhook", &e),
+            }
+        }
+        HookCommands::Test { owner, repo, id } => {
+            println!("Sending a test delivery to webhook {} in {}/{}...", id, owner, repo);
+            match client.test_hook(owner, repo, id).await {
+                Ok(_) => println!("Test delivery requested. Check the receiver or `gitee repo hooks get` for the result."),
+                Err(e) => report_error("Error testing webhook", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn hook_options(settings: &HookSettings) -> HookOptions {
+    let mut options = HookOptions {
+        auth: match (&settings.password, &settings.sign_key) {
+            (Some(password), _) => Some(HookAuth::Password(password.clone())),
+            (None, Some(key)) => Some(HookAuth::SignKey(key.clone())),
+            (None, None) => None,
+        },
+        ..Default::default()
+    };
+    if let Some(names) = &settings.events {
+        let events: Vec<HookEvent> = names.iter().filter_map(|name| HookEvent::parse(name)).collect();
+        options = options.with_events(&events);
+    }
+    options
+}
+
+fn print_hook(hook: &Hook) {
+    let events: Vec<&str> = hook.events().into_iter().map(HookEvent::as_str).collect();
+    println!("[{}] {}", hook.id, hook.url);
+    println!("  Events: {}", if events.is_empty() { "none".to_string() } else { events.join(", ") });
+    if let Some(code) = hook.result_code {
+        println!("  Last delivery: HTTP {}", code);
+    }
+}
@@ -0,0 +1,81 @@
+use clap::{Args, Subcommand};
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum HookCommands {
+    /// List webhooks of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+    },
+    /// Get a webhook
+    Get {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Webhook ID
+        id: String,
+    },
+    /// Create a webhook
+    Create {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// URL that receives the deliveries
+        #[arg(long)]
+        url: String,
+        #[command(flatten)]
+        settings: HookSettings,
+    },
+    /// Update a webhook
+    Update {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Webhook ID
+        id: String,
+        /// New delivery URL
+        #[arg(long)]
+        url: Option<String>,
+        #[command(flatten)]
+        settings: HookSettings,
+    },
+    /// Delete a webhook
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Webhook ID
+        id: String,
+    },
+    /// Send a test delivery to a webhook
+    Test {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Webhook ID
+        id: String,
+    },
+}
+
+#[derive(Args)]
+pub struct HookSettings {
+    /// Password sent with every delivery
+    #[arg(long, conflicts_with = "sign_key")]
+    pub password: Option<String>,
+    /// Key used to sign every delivery
+    #[arg(long)]
+    pub sign_key: Option<String>,
+    /// Comma separated events to subscribe to, replacing the current ones
+    #[arg(long, value_delimiter = ',', value_parser = ["push", "tag_push", "issues", "note", "merge_requests"])]
+    pub events: Option<Vec<String>>,
+}
@@ -1,5 +1,6 @@
 use gitee_rs::{GiteeClient, Issue};
 use anyhow::Result;
+use crate::errors::report_error;
 use super::{IssueCommands, IssueCommandsExtended};
 
 pub async fn handle_issues(client: &GiteeClient, cmd: &IssueCommands) -> Result<()> {
@@ -17,7 +18,7 @@ pub async fn handle_issues(client: &GiteeClient, cmd: &IssueCommands) -> Result<
                             }
                         }
                     }
-                    Err(e) => eprintln!("Error fetching repo issues: {}", e),
+                    Err(e) => report_error("Error fetching repo issues", &e),
                 }
             } else {
                 println!("Fetching your issues across all repositories...");
@@ -31,7 +32,7 @@ pub async fn handle_issues(client: &GiteeClient, cmd: &IssueCommands) -> Result<
                             }
                         }
                     }
-                    Err(e) => eprintln!("Error fetching issues: {}", e),
+                    Err(e) => report_error("Error fetching issues", &e),
                 }
             }
         }
@@ -42,7 +43,7 @@ pub async fn handle_issues(client: &GiteeClient, cmd: &IssueCommands) -> Result<
                     println!("Successfully created issue #{}: {}", issue.number, issue.title);
                     print_issue(&issue);
                 }
-                Err(e) => eprintln!("Error creating issue: {}", e),
+                Err(e) => report_error("Error creating issue", &e),
             }
         }
         IssueCommands::Close { owner, repo, number } => {
@@ -52,7 +53,7 @@ pub async fn handle_issues(client: &GiteeClient, cmd: &IssueCommands) -> Result<
                     println!("Successfully closed issue #{}: {}", issue.number, issue.title);
                     print_issue(&issue);
                 }
-                Err(e) => eprintln!("Error closing issue: {}", e),
+                Err(e) => report_error("Error closing issue", &e),
             }
         }
     }
@@ -67,7 +68,7 @@ pub async fn handle_issues_ext(client: &GiteeClient, cmd: &IssueCommandsExtended
                 Ok(issue) => {
                     print_issue(&issue);
                 }
-                Err(e) => eprintln!("Error fetching issue detail: {}", e),
+                Err(e) => report_error("Error fetching issue detail", &e),
             }
         }
         IssueCommandsExtended::Update { owner, repo, number, title, body, state } => {
@@ -77,7 +78,7 @@ pub async fn handle_issues_ext(client: &GiteeClient, cmd: &IssueCommandsExtended
                     println!("Successfully updated issue #{}: {}", issue.number, issue.title);
                     print_issue(&issue);
                 }
-                Err(e) => eprintln!("Error updating issue: {}", e),
+                Err(e) => report_error("Error updating issue", &e),
             }
         }
         IssueCommandsExtended::Comment { owner, repo, number, body } => {
@@ -89,7 +90,7 @@ pub async fn handle_issues_ext(client: &GiteeClient, cmd: &IssueCommandsExtended
                         println!("...");
                     }
                 }
-                Err(e) => eprintln!("Error commenting on issue: {}", e),
+                Err(e) => report_error("Error commenting on issue", &e),
             }
         }
         IssueCommandsExtended::ListComments { owner, repo, number } => {
@@ -108,7 +109,7 @@ pub async fn handle_issues_ext(client: &GiteeClient, cmd: &IssueCommandsExtended
                         }
                     }
                 }
-                Err(e) => eprintln!("Error listing issue comments: {}", e),
+                Err(e) => report_error("Error listing issue comments", &e),
             }
         }
         IssueCommandsExtended::MilestoneList { owner, repo, state } => {
@@ -0,0 +1,140 @@
+use gitee_rs::{DeployKey, GiteeClient, SshKey};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::{DeployKeyCommands, KeyInput, SshKeyCommands};
+
+pub async fn handle_deploy_keys(client: &GiteeClient, cmd: &DeployKeyCommands) -> Result<()> {
+    match cmd {
+        DeployKeyCommands::List { owner, repo, available } => {
+            println!("Fetching deploy keys for {}/{}...", owner, repo);
+            let result = if *available {
+                client.list_available_deploy_keys(owner, repo).await
+            } else {
+                client.list_deploy_keys(owner, repo).await
+            };
+            match result {
+                Ok(keys) => {
+                    if keys.is_empty() {
+                        println!("No deploy keys found.");
+                    } else {
+                        for key in keys {
+                            print_deploy_key(&key);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching deploy keys", &e),
+            }
+        }
+        DeployKeyCommands::Add { owner, repo, key, write } => {
+            let public_key = read_key(key)?;
+            println!("Adding {} deploy key '{}' to {}/{}...", if *write { "read-write" } else { "read-only" }, key.title, owner, repo);
+            match client.add_deploy_key(owner, repo, &key.title, &public_key, !*write).await {
+                Ok(key) => {
+                    println!("Successfully added deploy key:");
+                    print_deploy_key(&key);
+                }
+                Err(e) => report_error("Error adding deploy key", &e),
+            }
+        }
+        DeployKeyCommands::Enable { owner, repo, id } => {
+            println!("Enabling deploy key {} on {}/{}...", id, owner, repo);
+            match client.enable_deploy_key(owner, repo, id).await {
+                Ok(_) => println!("Successfully enabled deploy key."),
+                Err(e) => report_error("Error enabling deploy key", &e),
+            }
+        }
+        DeployKeyCommands::Disable { owner, repo, id } => {
+            println!("Disabling deploy key {} on {}/{}...", id, owner, repo);
+            match client.disable_deploy_key(owner, repo, id).await {
+                Ok(_) => println!("Successfully disabled deploy key."),
+                Err(e) => report_error("Error disabling deploy key", &e),
+            }
+        }
+        DeployKeyCommands::Delete { owner, repo, id } => {
+            println!("Deleting deploy key {} from {}/{}...", id, owner, repo);
+            match client.delete_deploy_key(owner, repo, id).await {
+                Ok(_) => println!("Successfully deleted deploy key."),
+                Err(e) => report_error("Error deleting deploy key", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_ssh_keys(client: &GiteeClient, cmd: &SshKeyCommands) -> Result<()> {
+    match cmd {
+        SshKeyCommands::List => {
+            println!("Fetching your SSH keys...");
+            match client.list_ssh_keys().await {
+                Ok(keys) => {
+                    if keys.is_empty() {
+                        println!("No SSH keys found.");
+                    } else {
+                        for key in keys {
+                            print_ssh_key(&key);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching SSH keys", &e),
+            }
+        }
+        SshKeyCommands::Get { id } => {
+            match client.get_ssh_key(id).await {
+                Ok(key) => {
+                    print_ssh_key(&key);
+                    println!("  {}", key.key);
+                }
+                Err(e) => report_error("Error fetching SSH key", &e),
+            }
+        }
+        SshKeyCommands::Add { key } => {
+            let public_key = read_key(key)?;
+            println!("Adding SSH key '{}'...", key.title);
+            match client.add_ssh_key(&key.title, &public_key).await {
+                Ok(key) => {
+                    println!("Successfully added SSH key:");
+                    print_ssh_key(&key);
+                }
+                Err(e) => report_error("Error adding SSH key", &e),
+            }
+        }
+        SshKeyCommands::Delete { id } => {
+            println!("Deleting SSH key {}...", id);
+            match client.delete_ssh_key(id).await {
+                Ok(_) => println!("Successfully deleted SSH key."),
+                Err(e) => report_error("Error deleting SSH key", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_key(input: &KeyInput) -> Result<String> {
+    match (&input.key, &input.key_file) {
+        (Some(key), _) => Ok(key.trim().to_string()),
+        (None, Some(file)) => Ok(std::fs::read_to_string(file)?.trim().to_string()),
+        (None, None) => anyhow::bail!("Either --key or --key-file is required"),
+    }
+}
+
+/// Shorten a public key to its type and the tail of the key body
+fn key_summary(key: &str) -> String {
+    let mut parts = key.split_whitespace();
+    let kind = parts.next().unwrap_or("");
+    let body = parts.next().unwrap_or("");
+    let tail = body.get(body.len().saturating_sub(12)..).unwrap_or(body);
+    format!("{} ...{}", kind, tail)
+}
+
+fn print_deploy_key(key: &DeployKey) {
+    let access = match key.read_only {
+        Some(true) => " [read-only]",
+        Some(false) => " [read-write]",
+        None => "",
+    };
+    println!("[{}] {}{} ({})", key.id, key.title, access, key_summary(&key.key));
+}
+
+fn print_ssh_key(key: &SshKey) {
+    println!("[{}] {} ({})", key.id, key.title, key_summary(&key.key));
+}
@@ -0,0 +1,91 @@
+use clap::{Args, Subcommand};
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum DeployKeyCommands {
+    /// List deploy keys of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// List keys from your other repositories that can be enabled here instead
+        #[arg(long)]
+        available: bool,
+    },
+    /// Add a deploy key
+    Add {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        #[command(flatten)]
+        key: KeyInput,
+        /// Allow the key to push
+        #[arg(long)]
+        write: bool,
+    },
+    /// Enable an existing deploy key on a repository
+    Enable {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Deploy key ID
+        id: String,
+    },
+    /// Disable a deploy key without deleting it
+    Disable {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Deploy key ID
+        id: String,
+    },
+    /// Delete a deploy key
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Deploy key ID
+        id: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SshKeyCommands {
+    /// List your SSH public keys
+    List,
+    /// Get one of your SSH public keys
+    Get {
+        /// Key ID
+        id: String,
+    },
+    /// Add an SSH public key to your account
+    Add {
+        #[command(flatten)]
+        key: KeyInput,
+    },
+    /// Delete one of your SSH public keys
+    Delete {
+        /// Key ID
+        id: String,
+    },
+}
+
+#[derive(Args)]
+pub struct KeyInput {
+    /// Title of the key
+    #[arg(long)]
+    pub title: String,
+    /// Public key text, e.g. "ssh-ed25519 AAAA... comment"
+    #[arg(long, conflicts_with = "key_file", required_unless_present = "key_file")]
+    pub key: Option<String>,
+    /// Read the public key from a file, e.g. ~/.ssh/id_ed25519.pub
+    #[arg(long)]
+    pub key_file: Option<String>,
+}
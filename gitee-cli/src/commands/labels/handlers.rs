@@ -1,5 +1,6 @@
 use gitee_rs::GiteeClient;
 use anyhow::Result;
+use crate::errors::report_error;
 use super::LabelCommands;
 
 pub async fn handle_labels(client: &GiteeClient, cmd: &LabelCommands) -> Result<()> {
@@ -17,7 +18,7 @@ pub async fn handle_labels(client: &GiteeClient, cmd: &LabelCommands) -> Result<
                         }
                     }
                 }
-                Err(e) => eprintln!("Error fetching labels: {}", e),
+                Err(e) => report_error("Error fetching labels", &e),
             }
         }
         LabelCommands::Create { owner, repo, name, color, description } => {
@@ -28,7 +29,7 @@ pub async fn handle_labels(client: &GiteeClient, cmd: &LabelCommands) -> Result<
                     println!("{}: {} [#{})", label.name, label.description.as_deref().unwrap_or(""), label.color);
                     println!("  URL: {}", label.url);
                 }
-                Err(e) => eprintln!("Error creating label: {}", e),
+                Err(e) => report_error("Error creating label", &e),
             }
         }
         LabelCommands::Update { owner, repo, name, new_name, color, description } => {
@@ -39,14 +40,14 @@ pub async fn handle_labels(client: &GiteeClient, cmd: &LabelCommands) -> Result<
                     println!("{}: {} [#{})", label.name, label.description.as_deref().unwrap_or(""), label.color);
                     println!("  URL: {}", label.url);
                 }
-                Err(e) => eprintln!("Error updating label: {}", e),
+                Err(e) => report_error("Error updating label", &e),
             }
         }
         LabelCommands::Delete { owner, repo, name } => {
             println!("Deleting label '{}' from {}/{}...", name, owner, repo);
             match client.delete_label(owner, repo, name).await {
                 Ok(_) => println!("Successfully deleted label."),
-                Err(e) => eprintln!("Error deleting label: {}", e),
+                Err(e) => report_error("Error deleting label", &e),
             }
         }
     }
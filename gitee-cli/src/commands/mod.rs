@@ -7,3 +7,11 @@ pub mod notifications;
 pub mod files;
 pub mod releases;
 pub mod wikis;
+pub mod branches;
+pub mod commits;
+pub mod tags;
+pub mod collaborators;
+pub mod orgs;
+pub mod enterprises;
+pub mod hooks;
+pub mod keys;
@@ -1,5 +1,6 @@
 use gitee_rs::GiteeClient;
 use anyhow::Result;
+use crate::errors::report_error;
 use super::NotificationCommands;
 
 pub async fn handle_notifications(client: &GiteeClient, cmd: &NotificationCommands) -> Result<()> {
@@ -25,7 +26,7 @@ pub async fn handle_notifications(client: &GiteeClient, cmd: &NotificationComman
                         }
                     }
                 }
-                Err(e) => eprintln!("Error fetching notifications: {}", e),
+                Err(e) => report_error("Error fetching notifications", &e),
             }
         }
     }
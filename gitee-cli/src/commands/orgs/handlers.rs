@@ -0,0 +1,107 @@
+use gitee_rs::{orgs::{OrgListOptions, OrgRepoListOptions}, GiteeClient, OrgMembership, OrgRole, Organization};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::OrgCommands;
+
+pub async fn handle_orgs(client: &GiteeClient, cmd: &OrgCommands) -> Result<()> {
+    match cmd {
+        OrgCommands::List { admin } => {
+            println!("Fetching your organizations...");
+            let options = OrgListOptions { admin: admin.then_some(true), ..Default::default() };
+            match client.paginate_user_orgs(Some(options)).collect_all(None).await {
+                Ok(orgs) => {
+                    if orgs.is_empty() {
+                        println!("No organizations found.");
+                    } else {
+                        for org in orgs {
+                            println!("{}: {}", org.login, org.name.as_deref().unwrap_or(""));
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching organizations", &e),
+            }
+        }
+        OrgCommands::Info { org } => {
+            match client.get_org(org).await {
+                Ok(org) => print_org(&org),
+                Err(e) => report_error("Error fetching organization", &e),
+            }
+        }
+        OrgCommands::Members { org, role } => {
+            println!("Fetching members of {}...", org);
+            let wanted = role.as_deref().and_then(OrgRole::parse);
+            match client.list_org_member_roles(org).await {
+                Ok(members) => {
+                    let members: Vec<_> = members
+                        .into_iter()
+                        .filter(|m| wanted.is_none_or(|role| m.role == role))
+                        .collect();
+                    if members.is_empty() {
+                        println!("No members found.");
+                    } else {
+                        for member in members {
+                            println!("{} [{}]", member.user.login, member.role.as_str());
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching organization members", &e),
+            }
+        }
+        OrgCommands::Invite { org, username, role } => {
+            let role = OrgRole::parse(role).unwrap_or(OrgRole::Member);
+            println!("Inviting {} to {} as {}...", username, org, role.as_str());
+            match client.invite_org_member(org, username, role).await {
+                Ok(membership) => print_membership(username, &membership),
+                Err(e) => report_error("Error inviting organization member", &e),
+            }
+        }
+        OrgCommands::Remove { org, username } => {
+            println!("Removing {} from {}...", username, org);
+            match client.remove_org_member(org, username).await {
+                Ok(_) => println!("Successfully removed member."),
+                Err(e) => report_error("Error removing organization member", &e),
+            }
+        }
+        OrgCommands::Repos { org, repo_type, all } => {
+            println!("Fetching repositories of {}...", org);
+            let options = OrgRepoListOptions { repo_type: repo_type.clone(), ..Default::default() };
+            let result = if *all {
+                client.paginate_org_repos(org, Some(options)).collect_all(None).await
+            } else {
+                client.list_org_repos(org, Some(options)).await
+            };
+            match result {
+                Ok(repos) => {
+                    if repos.is_empty() {
+                        println!("No repositories found.");
+                    } else {
+                        for repo in repos {
+                            println!("{}{} ({})", repo.full_name, if repo.private { " [private]" } else { "" }, repo.html_url);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching organization repositories", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn print_org(org: &Organization) {
+    println!("Organization: {} ({})", org.login, org.name.as_deref().unwrap_or(""));
+    if let Some(desc) = org.description.as_deref().filter(|d| !d.is_empty()) {
+        println!("Description: {}", desc);
+    }
+    if let Some(url) = &org.html_url {
+        println!("URL: {}", url);
+    }
+    if let Some(members) = org.members {
+        println!("Members: {}", members);
+    }
+    println!("Public repos: {}, Private repos: {}", org.public_repos.unwrap_or(0), org.private_repos.unwrap_or(0));
+}
+
+fn print_membership(username: &str, membership: &OrgMembership) {
+    let state = if membership.active { "active" } else { "pending invitation" };
+    println!("{} is {} ({})", username, membership.role, state);
+}
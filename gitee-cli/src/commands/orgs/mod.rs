@@ -0,0 +1,55 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum OrgCommands {
+    /// List your organizations
+    List {
+        /// Only organizations you administer
+        #[arg(long)]
+        admin: bool,
+    },
+    /// Get organization information
+    Info {
+        /// Organization path
+        org: String,
+    },
+    /// List organization members and their roles
+    Members {
+        /// Organization path
+        org: String,
+        /// Only list members with this role
+        #[arg(long, value_parser = ["admin", "member"])]
+        role: Option<String>,
+    },
+    /// Invite a user to an organization or change their role
+    Invite {
+        /// Organization path
+        org: String,
+        /// Username to invite
+        username: String,
+        /// Role to grant
+        #[arg(long, default_value = "member", value_parser = ["admin", "member"])]
+        role: String,
+    },
+    /// Remove a member from an organization
+    Remove {
+        /// Organization path
+        org: String,
+        /// Username to remove
+        username: String,
+    },
+    /// List repositories of an organization
+    Repos {
+        /// Organization path
+        org: String,
+        /// Repository visibility filter
+        #[arg(long = "type", value_parser = ["all", "public", "private"])]
+        repo_type: Option<String>,
+        /// Fetch every page instead of only the first
+        #[arg(long)]
+        all: bool,
+    },
+}
@@ -1,5 +1,7 @@
-use gitee_rs::{GiteeClient, PullRequest};
+use gitee_rs::{pulls::{DiffPosition, MergeMethod, MergeOptions}, GiteeClient, PullComment, PullRequest, PullReviewStatus};
 use anyhow::Result;
+use crate::commands::commits::print_commit;
+use crate::errors::report_error;
 use super::{PullRequestCommands, PullRequestCommandsExtended};
 
 pub async fn handle_pulls(client: &GiteeClient, cmd: &PullRequestCommands) -> Result<()> {
@@ -16,17 +18,34 @@ pub async fn handle_pulls(client: &GiteeClient, cmd: &PullRequestCommands) -> Re
                         }
                     }
                 }
-                Err(e) => eprintln!("Error fetching pull requests: {}", e),
+                Err(e) => report_error("Error fetching pull requests", &e),
             }
         }
         PullRequestCommands::Create { owner, repo, title, head, base, body } => {
-            println!("Creating pull request '{}' in {}/{}...", title, owner, repo);
-            match client.create_pull(owner, repo, title, head, base, body.as_deref()).await {
+            let base = match base {
+                Some(base) => base.clone(),
+                None => match client.get_repo(owner, repo).await {
+                    Ok(r) => r.default_branch.unwrap_or_else(|| "master".to_string()),
+                    Err(e) => {
+                        report_error("Error fetching repository default branch", &e);
+                        return Ok(());
+                    }
+                },
+            };
+            // "user:branch" refers to a fork and cannot be checked here
+            if !head.contains(':') {
+                if let Err(e) = client.get_branch(owner, repo, head).await {
+                    report_error(&format!("Error checking source branch '{}'", head), &e);
+                    return Ok(());
+                }
+            }
+            println!("Creating pull request '{}' in {}/{} ({} -> {})...", title, owner, repo, head, base);
+            match client.create_pull(owner, repo, title, head, &base, body.as_deref()).await {
                 Ok(pr) => {
                     println!("Successfully created pull request #{}: {}", pr.number, pr.title);
                     print_pull_request(&pr);
                 }
-                Err(e) => eprintln!("Error creating pull request: {}", e),
+                Err(e) => report_error("Error creating pull request", &e),
             }
         }
         PullRequestCommands::Close { owner, repo, number } => {
@@ -36,17 +55,105 @@ pub async fn handle_pulls(client: &GiteeClient, cmd: &PullRequestCommands) -> Re
                     println!("Successfully closed pull request #{}: {}", pr.number, pr.title);
                     print_pull_request(&pr);
                 }
-                Err(e) => eprintln!("Error closing pull request: {}", e),
+                Err(e) => report_error("Error closing pull request", &e),
             }
         }
-        PullRequestCommands::Merge { owner, repo, number } => {
+        PullRequestCommands::Merge { owner, repo, number, method, title, description, delete_branch, force } => {
+            if !*force {
+                match client.get_pull_detail(owner, repo, number).await {
+                    Ok(pr) => {
+                        let blockers = pr.merge_blockers();
+                        if !blockers.is_empty() {
+                            eprintln!("Refusing to merge pull request #{}:", number);
+                            for blocker in blockers {
+                                eprintln!("  - {}", blocker);
+                            }
+                            eprintln!("  Use --force to merge anyway.");
+                            return Ok(());
+                        }
+                    }
+                    Err(e) => {
+                        report_error("Error checking pull request before merging", &e);
+                        return Ok(());
+                    }
+                }
+            }
+            let options = MergeOptions {
+                merge_method: method.as_deref().and_then(MergeMethod::parse),
+                title: title.clone(),
+                description: description.clone(),
+                prune_source_branch: delete_branch.then_some(true),
+            };
             println!("Merging pull request #{} in {}/{}...", number, owner, repo);
-            match client.merge_pull(owner, repo, number).await {
+            match client.merge_pull(owner, repo, number, Some(options)).await {
                 Ok(pr) => {
                     println!("Successfully merged pull request #{}: {}", pr.number, pr.title);
                     print_pull_request(&pr);
                 }
-                Err(e) => eprintln!("Error merging pull request: {}", e),
+                Err(e) => report_error("Error merging pull request", &e),
+            }
+        }
+        PullRequestCommands::AddReviewers { owner, repo, number, users } => {
+            println!("Assigning reviewers to pull request #{} in {}/{}...", number, owner, repo);
+            match client.add_pull_reviewers(owner, repo, number, &as_strs(users)).await {
+                Ok(pr) => print_review_status(&PullReviewStatus::from_pull(&pr)),
+                Err(e) => report_error("Error assigning reviewers", &e),
+            }
+        }
+        PullRequestCommands::RemoveReviewers { owner, repo, number, users } => {
+            println!("Removing reviewers from pull request #{} in {}/{}...", number, owner, repo);
+            match client.remove_pull_reviewers(owner, repo, number, &as_strs(users)).await {
+                Ok(pr) => print_review_status(&PullReviewStatus::from_pull(&pr)),
+                Err(e) => report_error("Error removing reviewers", &e),
+            }
+        }
+        PullRequestCommands::AddTesters { owner, repo, number, users } => {
+            println!("Assigning testers to pull request #{} in {}/{}...", number, owner, repo);
+            match client.add_pull_testers(owner, repo, number, &as_strs(users)).await {
+                Ok(pr) => print_review_status(&PullReviewStatus::from_pull(&pr)),
+                Err(e) => report_error("Error assigning testers", &e),
+            }
+        }
+        PullRequestCommands::RemoveTesters { owner, repo, number, users } => {
+            println!("Removing testers from pull request #{} in {}/{}...", number, owner, repo);
+            match client.remove_pull_testers(owner, repo, number, &as_strs(users)).await {
+                Ok(pr) => print_review_status(&PullReviewStatus::from_pull(&pr)),
+                Err(e) => report_error("Error removing testers", &e),
+            }
+        }
+        PullRequestCommands::Approve { owner, repo, number, force } => {
+            println!("Approving review of pull request #{} in {}/{}...", number, owner, repo);
+            match client.approve_pull_review(owner, repo, number, *force).await {
+                Ok(_) => println!("Successfully approved the review."),
+                Err(e) => report_error("Error approving review", &e),
+            }
+        }
+        PullRequestCommands::PassTest { owner, repo, number, force } => {
+            println!("Passing test of pull request #{} in {}/{}...", number, owner, repo);
+            match client.pass_pull_test(owner, repo, number, *force).await {
+                Ok(_) => println!("Successfully marked the test as passed."),
+                Err(e) => report_error("Error passing test", &e),
+            }
+        }
+        PullRequestCommands::ResetReview { owner, repo, number, all } => {
+            println!("Resetting review of pull request #{} in {}/{}...", number, owner, repo);
+            match client.reset_pull_review(owner, repo, number, *all).await {
+                Ok(_) => println!("Successfully reset the review state."),
+                Err(e) => report_error("Error resetting review", &e),
+            }
+        }
+        PullRequestCommands::ResetTest { owner, repo, number, all } => {
+            println!("Resetting test of pull request #{} in {}/{}...", number, owner, repo);
+            match client.reset_pull_test(owner, repo, number, *all).await {
+                Ok(_) => println!("Successfully reset the test state."),
+                Err(e) => report_error("Error resetting test", &e),
+            }
+        }
+        PullRequestCommands::ReviewStatus { owner, repo, number } => {
+            println!("Fetching review status of pull request #{} in {}/{}...", number, owner, repo);
+            match client.get_pull_review_status(owner, repo, number).await {
+                Ok(status) => print_review_status(&status),
+                Err(e) => report_error("Error fetching review status", &e),
             }
         }
     }
@@ -60,8 +167,9 @@ pub async fn handle_pulls_ext(client: &GiteeClient, cmd: &PullRequestCommandsExt
             match client.get_pull_detail(owner, repo, number).await {
                 Ok(pr) => {
                     print_pull_request(&pr);
+                    print_pull_details(&pr);
                 }
-                Err(e) => eprintln!("Error fetching pull request detail: {}", e),
+                Err(e) => report_error("Error fetching pull request detail", &e),
             }
         }
         PullRequestCommandsExtended::Update { owner, repo, number, title, body, state } => {
@@ -71,7 +179,7 @@ pub async fn handle_pulls_ext(client: &GiteeClient, cmd: &PullRequestCommandsExt
                     println!("Successfully updated pull request #{}: {}", pr.number, pr.title);
                     print_pull_request(&pr);
                 }
-                Err(e) => eprintln!("Error updating pull request: {}", e),
+                Err(e) => report_error("Error updating pull request", &e),
             }
         }
         PullRequestCommandsExtended::Comment { owner, repo, number, body } => {
@@ -83,7 +191,7 @@ pub async fn handle_pulls_ext(client: &GiteeClient, cmd: &PullRequestCommandsExt
                         println!("...");
                     }
                 }
-                Err(e) => eprintln!("Error commenting on pull request: {}", e),
+                Err(e) => report_error("Error commenting on pull request", &e),
             }
         }
         PullRequestCommandsExtended::ListComments { owner, repo, number } => {
@@ -102,7 +210,87 @@ pub async fn handle_pulls_ext(client: &GiteeClient, cmd: &PullRequestCommandsExt
                         }
                     }
                 }
-                Err(e) => eprintln!("Error listing pull request comments: {}", e),
+                Err(e) => report_error("Error listing pull request comments", &e),
+            }
+        }
+        PullRequestCommandsExtended::ReviewComment { owner, repo, number, path, position, commit, body } => {
+            let commit_id = match commit {
+                Some(commit) => commit.clone(),
+                None => match client.get_pull_detail(owner, repo, number).await {
+                    Ok(pr) => pr.head.sha,
+                    Err(e) => {
+                        report_error("Error fetching pull request head", &e);
+                        return Ok(());
+                    }
+                },
+            };
+            let anchor = DiffPosition { commit_id, path: path.clone(), position: *position };
+            println!("Commenting on {}:{} in pull request #{} in {}/{}...", path, position, number, owner, repo);
+            match client.create_pull_review_comment(owner, repo, number, body, &anchor).await {
+                Ok(comment) => println!("Successfully added review comment {}.", comment.id),
+                Err(e) => report_error("Error adding review comment", &e),
+            }
+        }
+        PullRequestCommandsExtended::ListReviewComments { owner, repo, number } => {
+            println!("Listing review comments for pull request #{} in {}/{}...", number, owner, repo);
+            match client.list_pull_review_comments(owner, repo, number).await {
+                Ok(comments) => {
+                    if comments.is_empty() {
+                        println!("No review comments found.");
+                    } else {
+                        for comment in comments {
+                            print_review_comment(&comment);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error listing review comments", &e),
+            }
+        }
+        PullRequestCommandsExtended::ReplyComment { owner, repo, number, comment_id, body } => {
+            println!("Replying to comment {} in pull request #{} in {}/{}...", comment_id, number, owner, repo);
+            match client.reply_pull_review_comment(owner, repo, number, comment_id, body).await {
+                Ok(comment) => println!("Successfully added reply {}.", comment.id),
+                Err(e) => report_error("Error replying to comment", &e),
+            }
+        }
+        PullRequestCommandsExtended::EditComment { owner, repo, comment_id, body } => {
+            println!("Editing comment {} in {}/{}...", comment_id, owner, repo);
+            match client.update_pull_comment(owner, repo, comment_id, body).await {
+                Ok(_) => println!("Successfully edited comment."),
+                Err(e) => report_error("Error editing comment", &e),
+            }
+        }
+        PullRequestCommandsExtended::DeleteComment { owner, repo, comment_id } => {
+            println!("Deleting comment {} in {}/{}...", comment_id, owner, repo);
+            match client.delete_pull_comment(owner, repo, comment_id).await {
+                Ok(_) => println!("Successfully deleted comment."),
+                Err(e) => report_error("Error deleting comment", &e),
+            }
+        }
+        PullRequestCommandsExtended::Commits { owner, repo, number } => {
+            println!("Listing commits of pull request #{} in {}/{}...", number, owner, repo);
+            match client.list_pull_commits(owner, repo, number).await {
+                Ok(commits) => {
+                    if commits.is_empty() {
+                        println!("No commits found.");
+                    } else {
+                        for commit in commits {
+                            print_commit(&commit);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error listing pull request commits", &e),
+            }
+        }
+        PullRequestCommandsExtended::Diff { owner, repo, number, patch } => {
+            let result = if *patch {
+                client.get_pull_patch(owner, repo, number).await
+            } else {
+                client.get_pull_diff(owner, repo, number).await
+            };
+            match result {
+                Ok(text) => print!("{}", text),
+                Err(e) => report_error("Error fetching pull request diff", &e),
             }
         }
         PullRequestCommandsExtended::DiffFiles { owner, repo, number } => {
@@ -117,7 +305,7 @@ pub async fn handle_pulls_ext(client: &GiteeClient, cmd: &PullRequestCommandsExt
                         }
                     }
                 }
-                Err(e) => eprintln!("Error getting diff files: {}", e),
+                Err(e) => report_error("Error getting diff files", &e),
             }
         }
     }
@@ -137,3 +325,53 @@ pub fn print_pull_request(pr: &PullRequest) {
     println!("  Source: {} -> {}", pr.head.label, pr.base.label);
     println!();
 }
+
+fn print_pull_details(pr: &PullRequest) {
+    let mergeable = match pr.mergeable {
+        Some(true) => "yes",
+        Some(false) => "no (conflicts)",
+        None => "unknown",
+    };
+    println!("  Draft: {}, Mergeable: {}", if pr.draft { "yes" } else { "no" }, mergeable);
+    if let Some(merged_at) = &pr.merged_at {
+        println!("  Merged at: {} ({})", merged_at, pr.merge_commit_sha.as_deref().unwrap_or("unknown commit"));
+    }
+    if !pr.labels.is_empty() {
+        let labels: Vec<&str> = pr.labels.iter().map(|l| l.name.as_str()).collect();
+        println!("  Labels: {}", labels.join(", "));
+    }
+    if let Some(milestone) = &pr.milestone {
+        println!("  Milestone: {}", milestone.title);
+    }
+    print_review_status(&PullReviewStatus::from_pull(pr));
+}
+
+fn as_strs(users: &[String]) -> Vec<&str> {
+    users.iter().map(|u| u.as_str()).collect()
+}
+
+pub fn print_review_status(status: &PullReviewStatus) {
+    println!("Review: {}/{} approvals{}", status.approvals, status.required_approvals,
+        if status.review_passed { " (passed)" } else { "" });
+    for reviewer in &status.reviewers {
+        println!("  [{}] {}", if reviewer.accept { "x" } else { " " }, reviewer.login);
+    }
+    println!("Test: {}/{} passes{}", status.tests_passed, status.required_tests,
+        if status.test_passed { " (passed)" } else { "" });
+    for tester in &status.testers {
+        println!("  [{}] {}", if tester.accept { "x" } else { " " }, tester.login);
+    }
+}
+
+fn print_review_comment(comment: &PullComment) {
+    let location = match (&comment.path, comment.position) {
+        (Some(path), Some(position)) => format!("{}:{}", path, position),
+        (Some(path), None) => path.clone(),
+        _ => "(outdated)".to_string(),
+    };
+    let reply = comment.in_reply_to.as_deref().map(|id| format!(" (reply to {})", id)).unwrap_or_default();
+    println!("[{}] {} by {}{}: {}", comment.id, location,
+        comment.user.as_ref().map(|u| u.login.as_str()).unwrap_or("unknown"),
+        reply,
+        comment.body.lines().next().unwrap_or(""));
+}
@@ -23,9 +23,9 @@ pub enum PullRequestCommands {
         /// Source branch
         #[arg(long, default_value = "main")]
         head: String,
-        /// Target branch
-        #[arg(long, default_value = "master")]
-        base: String,
+        /// Target branch (defaults to the repository's default branch)
+        #[arg(long)]
+        base: Option<String>,
         /// Body of the pull request
         #[arg(short, long)]
         body: Option<String>,
@@ -47,6 +47,126 @@ pub enum PullRequestCommands {
         repo: String,
         /// Pull request number
         number: String,
+        /// Merge strategy
+        #[arg(long, value_parser = ["merge", "squash", "rebase"])]
+        method: Option<String>,
+        /// Merge commit title
+        #[arg(long)]
+        title: Option<String>,
+        /// Merge commit description
+        #[arg(long)]
+        description: Option<String>,
+        /// Delete the source branch after merging
+        #[arg(long)]
+        delete_branch: bool,
+        /// Merge even if the pull request has conflicts or is missing required reviews
+        #[arg(long)]
+        force: bool,
+    },
+    /// Assign reviewers to a pull request
+    AddReviewers {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Usernames of the reviewers
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Remove reviewers from a pull request
+    RemoveReviewers {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Usernames of the reviewers
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Assign testers to a pull request
+    AddTesters {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Usernames of the testers
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Remove testers from a pull request
+    RemoveTesters {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Usernames of the testers
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Approve the review of a pull request (审查通过)
+    Approve {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Pass on behalf of everyone (repository admins only)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Mark the test of a pull request as passed (测试通过)
+    PassTest {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Pass on behalf of everyone (repository admins only)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Reset the review state of a pull request
+    ResetReview {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Also reset the state of everyone else, not just your own
+        #[arg(long)]
+        all: bool,
+    },
+    /// Reset the test state of a pull request
+    ResetTest {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Also reset the state of everyone else, not just your own
+        #[arg(long)]
+        all: bool,
+    },
+    /// Show the review and test progress of a pull request
+    ReviewStatus {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
     },
 }
 
@@ -99,6 +219,89 @@ pub enum PullRequestCommandsExtended {
         /// Pull request number
         number: String,
     },
+    /// Comment on a specific line of a pull request diff
+    ReviewComment {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// File path in the diff
+        #[arg(long)]
+        path: String,
+        /// Line index within the file's diff
+        #[arg(long)]
+        position: i32,
+        /// Commit sha to comment on (defaults to the pull request head)
+        #[arg(long)]
+        commit: Option<String>,
+        /// Comment body
+        body: String,
+    },
+    /// List line-level review comments of a pull request
+    ListReviewComments {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+    },
+    /// Reply to a review comment
+    ReplyComment {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// ID of the comment to reply to
+        comment_id: String,
+        /// Reply body
+        body: String,
+    },
+    /// Edit a pull request comment
+    EditComment {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Comment ID
+        comment_id: String,
+        /// New comment body
+        body: String,
+    },
+    /// Delete a pull request comment
+    DeleteComment {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Comment ID
+        comment_id: String,
+    },
+    /// List the commits of a pull request
+    Commits {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+    },
+    /// Print the unified diff of a pull request
+    Diff {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Pull request number
+        number: String,
+        /// Print format-patch output instead of a plain diff
+        #[arg(long)]
+        patch: bool,
+    },
     /// Get diff files for a pull request
     DiffFiles {
         /// Owner of the repository
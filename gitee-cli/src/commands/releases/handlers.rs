@@ -1,5 +1,7 @@
-use gitee_rs::GiteeClient;
+use gitee_rs::{releases::{ReleaseOptions, ReleaseUpdate}, Asset, GiteeClient, Release};
 use anyhow::Result;
+use std::path::Path;
+use crate::errors::report_error;
 use super::ReleaseCommands;
 
 pub async fn handle_releases(client: &GiteeClient, cmd: &ReleaseCommands) -> Result<()> {
@@ -20,19 +22,166 @@ pub async fn handle_releases(client: &GiteeClient, cmd: &ReleaseCommands) -> Res
                         }
                     }
                 }
-                Err(e) => eprintln!("Error listing releases: {}", e),
+                Err(e) => report_error("Error listing releases", &e),
             }
         }
-        ReleaseCommands::Create { owner, repo, tag_name, name, body } => {
+        ReleaseCommands::Create { owner, repo, tag_name, name, body, target, prerelease, draft } => {
             println!("Creating release '{}' for tag {} in {}/{}...", name, tag_name, owner, repo);
-            match client.create_release(owner, repo, tag_name, name, body.as_deref()).await {
+            let options = ReleaseOptions {
+                target_commitish: target.clone(),
+                prerelease: Some(*prerelease),
+                draft: draft.then_some(true),
+            };
+            match client.create_release(owner, repo, tag_name, name, body.as_deref(), Some(options)).await {
                 Ok(release) => {
                     println!("Successfully created release: {}", release.name);
                     println!("Tag: {}", release.tag_name);
+                    println!("ID: {}", release.id);
                 }
-                Err(e) => eprintln!("Error creating release: {}", e),
+                Err(e) => report_error("Error creating release", &e),
+            }
+        }
+        ReleaseCommands::Get { owner, repo, id } => {
+            println!("Fetching release {} in {}/{}...", id, owner, repo);
+            match client.get_release(owner, repo, id).await {
+                Ok(release) => print_release(&release),
+                Err(e) => report_error("Error fetching release", &e),
+            }
+        }
+        ReleaseCommands::Latest { owner, repo } => {
+            println!("Fetching latest release in {}/{}...", owner, repo);
+            match client.get_latest_release(owner, repo).await {
+                Ok(release) => print_release(&release),
+                Err(e) => report_error("Error fetching latest release", &e),
+            }
+        }
+        ReleaseCommands::GetByTag { owner, repo, tag } => {
+            println!("Fetching release for tag {} in {}/{}...", tag, owner, repo);
+            match client.get_release_by_tag(owner, repo, tag).await {
+                Ok(release) => print_release(&release),
+                Err(e) => report_error("Error fetching release", &e),
+            }
+        }
+        ReleaseCommands::Update { owner, repo, id, tag_name, name, body, prerelease } => {
+            println!("Updating release {} in {}/{}...", id, owner, repo);
+            let update = ReleaseUpdate {
+                tag_name: tag_name.clone(),
+                name: name.clone(),
+                body: body.clone(),
+                prerelease: *prerelease,
+            };
+            match client.update_release(owner, repo, id, update).await {
+                Ok(release) => {
+                    println!("Successfully updated release:");
+                    print_release(&release);
+                }
+                Err(e) => report_error("Error updating release", &e),
+            }
+        }
+        ReleaseCommands::Delete { owner, repo, id } => {
+            println!("Deleting release {} in {}/{}...", id, owner, repo);
+            match client.delete_release(owner, repo, id).await {
+                Ok(_) => println!("Successfully deleted release."),
+                Err(e) => report_error("Error deleting release", &e),
+            }
+        }
+        ReleaseCommands::Assets { owner, repo, id } => {
+            println!("Listing attachments of release {} in {}/{}...", id, owner, repo);
+            match client.list_release_assets(owner, repo, id).await {
+                Ok(assets) => {
+                    if assets.is_empty() {
+                        println!("No attachments found.");
+                    } else {
+                        for asset in assets {
+                            print_asset(&asset);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error listing attachments", &e),
+            }
+        }
+        ReleaseCommands::Upload { owner, repo, id, files } => {
+            for file in files {
+                println!("Uploading {} to release {} in {}/{}...", file, id, owner, repo);
+                match client.upload_release_asset(owner, repo, id, file).await {
+                    Ok(asset) => {
+                        println!("Successfully uploaded attachment:");
+                        print_asset(&asset);
+                    }
+                    Err(e) => {
+                        report_error("Error uploading attachment", &e);
+                        break;
+                    }
+                }
+            }
+        }
+        ReleaseCommands::Download { owner, repo, id, asset_id, output } => {
+            let output = match output {
+                Some(output) => output.clone(),
+                None => match client.get_release_asset(owner, repo, id, asset_id).await {
+                    Ok(asset) => match local_file_name(&asset.name) {
+                        Some(name) => name,
+                        None => {
+                            eprintln!("Attachment name '{}' is not a usable file name; pass --output.", asset.name);
+                            return Ok(());
+                        }
+                    },
+                    Err(e) => {
+                        report_error("Error fetching attachment", &e);
+                        return Ok(());
+                    }
+                },
+            };
+            println!("Downloading attachment {} to {}...", asset_id, output);
+            let mut file = tokio::fs::File::create(&output).await?;
+            match client.download_release_asset(owner, repo, id, asset_id, &mut file).await {
+                Ok(bytes) => println!("Successfully downloaded {} bytes.", bytes),
+                Err(e) => {
+                    drop(file);
+                    let _ = tokio::fs::remove_file(&output).await;
+                    report_error("Error downloading attachment", &e);
+                }
+            }
+        }
+        ReleaseCommands::DeleteAsset { owner, repo, id, asset_id } => {
+            println!("Deleting attachment {} of release {} in {}/{}...", asset_id, id, owner, repo);
+            match client.delete_release_asset(owner, repo, id, asset_id).await {
+                Ok(_) => println!("Successfully deleted attachment."),
+                Err(e) => report_error("Error deleting attachment", &e),
             }
         }
     }
     Ok(())
 }
+
+pub fn print_release(release: &Release) {
+    println!("Release {}: {} [{}]", release.id, release.name, release.tag_name);
+    println!("  Target: {}", release.target_commitish);
+    if release.prerelease {
+        println!("  Pre-release");
+    }
+    println!("  Created: {}", release.created_at);
+    if let Some(body) = &release.body {
+        println!("  {}", body.chars().take(100).collect::<String>());
+    }
+    for asset in &release.assets {
+        print_asset(asset);
+    }
+}
+
+fn print_asset(asset: &Asset) {
+    let id = if asset.id.is_empty() { "-" } else { asset.id.as_str() };
+    match asset.size {
+        Some(size) => println!("  [{}] {} ({} bytes) {}", id, asset.name, size, asset.browser_download_url),
+        None => println!("  [{}] {} {}", id, asset.name, asset.browser_download_url),
+    }
+}
+
+/// Keep only the last component of a server-supplied name so downloads stay in the working directory
+fn local_file_name(name: &str) -> Option<String> {
+    let name = Path::new(name).file_name()?.to_str()?;
+    match name {
+        "" | "." | ".." => None,
+        name => Some(name.to_string()),
+    }
+}
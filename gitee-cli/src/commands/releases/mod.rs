@@ -25,5 +25,115 @@ pub enum ReleaseCommands {
         /// Description of the release (optional)
         #[arg(short, long)]
         body: Option<String>,
+        /// Branch or commit to tag (defaults to the default branch)
+        #[arg(long)]
+        target: Option<String>,
+        /// Mark the release as a pre-release
+        #[arg(long)]
+        prerelease: bool,
+        /// Create the release as a draft
+        #[arg(long)]
+        draft: bool,
+    },
+    /// Get a release by id
+    Get {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+    },
+    /// Get the latest release
+    Latest {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+    },
+    /// Get the release for a tag
+    GetByTag {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Tag name
+        tag: String,
+    },
+    /// Update a release
+    Update {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+        /// New tag name (optional)
+        #[arg(long)]
+        tag_name: Option<String>,
+        /// New release name (optional)
+        #[arg(long)]
+        name: Option<String>,
+        /// New description (optional)
+        #[arg(short, long)]
+        body: Option<String>,
+        /// Set or clear the pre-release flag (optional)
+        #[arg(long)]
+        prerelease: Option<bool>,
+    },
+    /// Delete a release
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+    },
+    /// List files attached to a release
+    Assets {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+    },
+    /// Upload local files as release attachments
+    Upload {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+        /// Files to upload
+        #[arg(required = true)]
+        files: Vec<String>,
+    },
+    /// Download a release attachment
+    Download {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+        /// Attachment ID
+        asset_id: String,
+        /// Output file (defaults to the attachment name)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Delete a release attachment
+    DeleteAsset {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Release ID
+        id: String,
+        /// Attachment ID
+        asset_id: String,
     },
 }
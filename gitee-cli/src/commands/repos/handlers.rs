@@ -1,6 +1,16 @@
-use gitee_rs::{GiteeClient, Repository};
+use gitee_rs::{
+    insights::{ContributorType, ForkListOptions, ForkSort},
+    pulls::MergeMethod,
+    repos::RepoUpdate,
+    ArchiveFormat, GiteeClient, Repository,
+};
 use anyhow::Result;
-use super::{RepoCommands, RepoCommandsExtended};
+use crate::commands::collaborators::handle_collaborators;
+use crate::commands::hooks::handle_hooks;
+use crate::commands::keys::handle_deploy_keys;
+use crate::errors::report_error;
+use crate::prompt::{confirm, confirm_typed};
+use super::{RepoCommands, RepoCommandsExtended, RepoSettings};
 
 pub async fn handle_repos(client: &GiteeClient, cmd: &RepoCommands) -> Result<()> {
     match cmd {
@@ -16,7 +26,7 @@ pub async fn handle_repos(client: &GiteeClient, cmd: &RepoCommands) -> Result<()
                         }
                     }
                 }
-                Err(e) => eprintln!("Error fetching repositories: {}", e),
+                Err(e) => report_error("Error fetching repositories", &e),
             }
         }
         RepoCommands::Info { owner, repo } => {
@@ -25,7 +35,7 @@ pub async fn handle_repos(client: &GiteeClient, cmd: &RepoCommands) -> Result<()
                 Ok(repo) => {
                     print_repo(&repo);
                 }
-                Err(e) => eprintln!("Error fetching repository info: {}", e),
+                Err(e) => report_error("Error fetching repository info", &e),
             }
         }
         RepoCommands::Create { name, description, private } => {
@@ -35,16 +45,82 @@ pub async fn handle_repos(client: &GiteeClient, cmd: &RepoCommands) -> Result<()
                     println!("Successfully created repository:");
                     print_repo(&repo);
                 }
-                Err(e) => eprintln!("Error creating repository: {}", e),
+                Err(e) => report_error("Error creating repository", &e),
             }
         }
         RepoCommands::Delete { owner, repo } => {
             println!("Deleting repository {}/{}...", owner, repo);
             match client.delete_repo(owner, repo).await {
                 Ok(_) => println!("Successfully deleted repository."),
-                Err(e) => eprintln!("Error deleting repository: {}", e),
+                Err(e) => report_error("Error deleting repository", &e),
             }
         }
+        RepoCommands::Edit { owner, repo, settings, yes } => {
+            let update = repo_update(settings);
+            let changes = serde_json::to_value(&update)?;
+            let changes = changes.as_object().cloned().unwrap_or_default();
+            if changes.is_empty() {
+                println!("Nothing to change; pass at least one setting (see --help).");
+                return Ok(());
+            }
+            println!("The following settings of {}/{} will change:", owner, repo);
+            for (key, value) in &changes {
+                println!("  {}: {}", key, value);
+            }
+            if !*yes && !confirm("Apply these changes?")? {
+                println!("Aborted.");
+                return Ok(());
+            }
+            match client.update_repo(owner, repo, update).await {
+                Ok(repo) => {
+                    println!("Successfully updated repository:");
+                    print_repo(&repo);
+                }
+                Err(e) => report_error("Error updating repository", &e),
+            }
+        }
+        RepoCommands::Transfer { owner, repo, new_owner, yes } => {
+            let full_name = format!("{}/{}", owner, repo);
+            let warning = format!(
+                "Transferring {} to '{}' moves its issues, pull requests and permissions to the new owner.",
+                full_name, new_owner
+            );
+            if !*yes && !confirm_typed(&warning, &full_name)? {
+                println!("Aborted.");
+                return Ok(());
+            }
+            println!("Transferring {} to {}...", full_name, new_owner);
+            match client.transfer_repo(owner, repo, new_owner).await {
+                Ok(repo) => {
+                    println!("Successfully transferred repository:");
+                    print_repo(&repo);
+                }
+                Err(e) => report_error("Error transferring repository", &e),
+            }
+        }
+        RepoCommands::Archive { owner, repo, r#ref, output, format } => {
+            let format = match format.as_deref() {
+                Some("zip") => ArchiveFormat::Zip,
+                Some(_) => ArchiveFormat::TarGz,
+                None => ArchiveFormat::from_file_name(output).unwrap_or(ArchiveFormat::TarGz),
+            };
+            println!("Downloading {:?} archive of {}/{} at {} to {}...", format, owner, repo, r#ref, output);
+            let mut file = tokio::fs::File::create(output).await?;
+            match client.download_archive(owner, repo, r#ref, format, &mut file).await {
+                Ok(bytes) => println!("Successfully downloaded {} bytes.", bytes),
+                Err(e) => {
+                    drop(file);
+                    let _ = tokio::fs::remove_file(output).await;
+                    report_error("Error downloading archive", &e);
+                }
+            }
+        }
+        RepoCommands::Stats { owner, repo, limit, fork_sort, contributor_type } => {
+            print_repo_stats(client, owner, repo, *limit, fork_sort, contributor_type).await;
+        }
+        RepoCommands::Collaborators { cmd } => handle_collaborators(client, cmd).await?,
+        RepoCommands::Hooks { cmd } => handle_hooks(client, cmd).await?,
+        RepoCommands::DeployKeys { cmd } => handle_deploy_keys(client, cmd).await?,
     }
     Ok(())
 }
@@ -58,7 +134,7 @@ pub async fn handle_repos_ext(client: &GiteeClient, cmd: &RepoCommandsExtended)
                     println!("Successfully forked repository:");
                     print_repo(&forked);
                 }
-                Err(e) => eprintln!("Error forking repository: {}", e),
+                Err(e) => report_error("Error forking repository", &e),
             }
         }
         RepoCommandsExtended::Star { owner, repo } => {
@@ -85,6 +161,90 @@ pub async fn handle_repos_ext(client: &GiteeClient, cmd: &RepoCommandsExtended)
     Ok(())
 }
 
+/// Print each insight section in turn; a failing section is reported without hiding the others
+async fn print_repo_stats(client: &GiteeClient, owner: &str, repo: &str, limit: usize, fork_sort: &str, contributor_type: &str) {
+    println!("Fetching statistics for {}/{}...", owner, repo);
+    match client.get_repo(owner, repo).await {
+        Ok(r) => println!("Stars: {}  Watchers: {}  Forks: {}", r.stargazers_count, r.watchers_count, r.forks_count),
+        Err(e) => report_error("Error fetching repository info", &e),
+    }
+
+    println!("\nLanguages:");
+    match client.list_languages(owner, repo).await {
+        Ok(languages) if languages.is_empty() => println!("  (none detected)"),
+        Ok(languages) => {
+            for language in languages.iter().take(limit) {
+                println!("  {:<20} {:>6.1}%", language.name, language.percent);
+            }
+        }
+        Err(e) => report_error("Error fetching languages", &e),
+    }
+
+    println!("\nTop contributors:");
+    match client.list_contributors(owner, repo, ContributorType::parse(contributor_type)).await {
+        Ok(contributors) if contributors.is_empty() => println!("  (none)"),
+        Ok(contributors) => {
+            for contributor in contributors.iter().take(limit) {
+                let email = contributor.email.as_deref().map(|e| format!(" <{}>", e)).unwrap_or_default();
+                println!("  {:>6} commits  {}{}", contributor.contributions, contributor.name, email);
+            }
+        }
+        Err(e) => report_error("Error fetching contributors", &e),
+    }
+
+    println!("\nRecent stargazers:");
+    match client.paginate_stargazers(owner, repo).collect_all(Some(limit)).await {
+        Ok(stargazers) if stargazers.is_empty() => println!("  (none)"),
+        Ok(stargazers) => {
+            for stargazer in &stargazers {
+                println!("  {:<24} {}", stargazer.user.login, stargazer.star_at.as_deref().unwrap_or(""));
+            }
+        }
+        Err(e) => report_error("Error fetching stargazers", &e),
+    }
+
+    println!("\nRecent watchers:");
+    match client.paginate_watchers(owner, repo).collect_all(Some(limit)).await {
+        Ok(watchers) if watchers.is_empty() => println!("  (none)"),
+        Ok(watchers) => {
+            for watcher in &watchers {
+                println!("  {:<24} {}", watcher.user.login, watcher.watch_at.as_deref().unwrap_or(""));
+            }
+        }
+        Err(e) => report_error("Error fetching watchers", &e),
+    }
+
+    println!("\nForks ({}):", fork_sort);
+    let options = ForkListOptions { sort: ForkSort::parse(fork_sort), ..Default::default() };
+    match client.paginate_forks(owner, repo, Some(options)).collect_all(Some(limit)).await {
+        Ok(forks) if forks.is_empty() => println!("  (none)"),
+        Ok(forks) => {
+            for fork in &forks {
+                println!("  {:<40} ★ {:<5} {}", fork.full_name, fork.stargazers_count, fork.updated_at);
+            }
+        }
+        Err(e) => report_error("Error fetching forks", &e),
+    }
+}
+
+fn repo_update(settings: &RepoSettings) -> RepoUpdate {
+    RepoUpdate {
+        name: settings.name.clone(),
+        path: settings.path.clone(),
+        description: settings.description.clone(),
+        homepage: settings.homepage.clone(),
+        default_branch: settings.default_branch.clone(),
+        private: settings.visibility.as_deref().map(|v| v == "private"),
+        has_issues: settings.issues,
+        has_wiki: settings.wiki,
+        pull_requests_enabled: settings.pull_requests,
+        merge_enabled: settings.merge,
+        squash_enabled: settings.squash,
+        rebase_enabled: settings.rebase,
+        default_merge_method: settings.default_merge_method.as_deref().and_then(MergeMethod::parse),
+    }
+}
+
 pub fn print_repo(repo: &Repository) {
     println!("Name: {} ({})", repo.name, repo.full_name);
     if let Some(desc) = &repo.description {
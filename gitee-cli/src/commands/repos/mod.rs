@@ -1,7 +1,7 @@
 mod handlers;
 pub use handlers::*;
 
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 
 #[derive(Subcommand)]
 pub enum RepoCommands {
@@ -31,9 +31,123 @@ pub enum RepoCommands {
         owner: String,
         /// Name of the repository
         repo: String,
+    },
+    /// Change repository settings
+    Edit {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        #[command(flatten)]
+        settings: RepoSettings,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Transfer a repository to another user or organization
+    Transfer {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// User or organization path that receives the repository
+        new_owner: String,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Download an archive of the repository at a branch, tag or commit
+    Archive {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Branch, tag or commit to archive
+        #[arg(long = "ref")]
+        r#ref: String,
+        /// Output file; the format is taken from its extension (.zip or .tar.gz)
+        #[arg(short, long)]
+        output: String,
+        /// Archive format, overriding the output extension
+        #[arg(long, value_parser = ["zip", "tar.gz"])]
+        format: Option<String>,
+    },
+    /// Show languages, contributors, stargazers, watchers and forks of a repository
+    Stats {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Maximum number of entries shown in each list
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+        /// Order of the forks list
+        #[arg(long, default_value = "newest", value_parser = ["newest", "oldest", "stargazers"])]
+        fork_sort: String,
+        /// Count contributions by committer or by author
+        #[arg(long, default_value = "committers", value_parser = ["committers", "authors"])]
+        contributor_type: String,
+    },
+    /// Manage repository collaborators
+    Collaborators {
+        #[command(subcommand)]
+        cmd: crate::commands::collaborators::CollaboratorCommands,
+    },
+    /// Manage repository webhooks
+    Hooks {
+        #[command(subcommand)]
+        cmd: crate::commands::hooks::HookCommands,
+    },
+    /// Manage repository deploy keys
+    DeployKeys {
+        #[command(subcommand)]
+        cmd: crate::commands::keys::DeployKeyCommands,
     }
 }
 
+#[derive(Args)]
+pub struct RepoSettings {
+    /// New display name
+    #[arg(long)]
+    pub name: Option<String>,
+    /// New URL path
+    #[arg(long)]
+    pub path: Option<String>,
+    /// New description
+    #[arg(long)]
+    pub description: Option<String>,
+    /// New homepage URL
+    #[arg(long)]
+    pub homepage: Option<String>,
+    /// New default branch
+    #[arg(long)]
+    pub default_branch: Option<String>,
+    /// Repository visibility
+    #[arg(long, value_parser = ["public", "private"])]
+    pub visibility: Option<String>,
+    /// Enable or disable issues
+    #[arg(long)]
+    pub issues: Option<bool>,
+    /// Enable or disable the wiki
+    #[arg(long)]
+    pub wiki: Option<bool>,
+    /// Enable or disable pull requests
+    #[arg(long)]
+    pub pull_requests: Option<bool>,
+    /// Allow merge commits
+    #[arg(long)]
+    pub merge: Option<bool>,
+    /// Allow squash merging
+    #[arg(long)]
+    pub squash: Option<bool>,
+    /// Allow rebase merging
+    #[arg(long)]
+    pub rebase: Option<bool>,
+    /// Merge method preselected on pull requests
+    #[arg(long, value_parser = ["merge", "squash", "rebase"])]
+    pub default_merge_method: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum RepoCommandsExtended {
     /// Fork a repository
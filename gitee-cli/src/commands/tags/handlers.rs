@@ -0,0 +1,56 @@
+use gitee_rs::{GiteeClient, Tag};
+use anyhow::Result;
+use crate::errors::report_error;
+use super::TagCommands;
+
+pub async fn handle_tags(client: &GiteeClient, cmd: &TagCommands) -> Result<()> {
+    match cmd {
+        TagCommands::List { owner, repo } => {
+            println!("Fetching tags for {}/{}...", owner, repo);
+            match client.list_tags(owner, repo).await {
+                Ok(tags) => {
+                    if tags.is_empty() {
+                        println!("No tags found.");
+                    } else {
+                        for tag in tags {
+                            print_tag(&tag);
+                        }
+                    }
+                }
+                Err(e) => report_error("Error fetching tags", &e),
+            }
+        }
+        TagCommands::Create { owner, repo, tag_name, refs, message } => {
+            println!("Creating tag '{}' on '{}' in {}/{}...", tag_name, refs, owner, repo);
+            match client.create_tag(owner, repo, tag_name, refs, message.as_deref()).await {
+                Ok(tag) => {
+                    println!("Successfully created tag:");
+                    print_tag(&tag);
+                }
+                Err(e) => report_error("Error creating tag", &e),
+            }
+        }
+        TagCommands::Delete { owner, repo, tag_name } => {
+            println!("Deleting tag '{}' in {}/{}...", tag_name, owner, repo);
+            match client.delete_tag(owner, repo, tag_name).await {
+                Ok(_) => println!("Successfully deleted tag."),
+                Err(e) => report_error("Error deleting tag", &e),
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn print_tag(tag: &Tag) {
+    let short_sha = tag.commit.sha.get(..7).unwrap_or(&tag.commit.sha);
+    println!("{} -> {}{}", tag.name, short_sha, if tag.is_annotated() { " (annotated)" } else { "" });
+    if let Some(tagger) = &tag.tagger {
+        println!("  Tagger: {} <{}> at {}",
+            tagger.name.as_deref().unwrap_or("unknown"),
+            tagger.email.as_deref().unwrap_or(""),
+            tagger.date.as_deref().unwrap_or(""));
+    }
+    if let Some(message) = tag.message.as_deref().filter(|m| !m.trim().is_empty()) {
+        println!("  Message: {}", message.lines().next().unwrap_or(""));
+    }
+}
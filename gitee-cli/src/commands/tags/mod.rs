@@ -0,0 +1,39 @@
+use clap::Subcommand;
+
+mod handlers;
+pub use handlers::*;
+
+#[derive(Subcommand)]
+pub enum TagCommands {
+    /// List tags of a repository
+    List {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+    },
+    /// Create a tag on a branch or commit
+    Create {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Name of the new tag
+        tag_name: String,
+        /// Branch or commit to tag
+        #[arg(long = "ref")]
+        refs: String,
+        /// Tag message; creates an annotated tag when set
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+    /// Delete a tag
+    Delete {
+        /// Owner of the repository
+        owner: String,
+        /// Name of the repository
+        repo: String,
+        /// Tag name
+        tag_name: String,
+    },
+}
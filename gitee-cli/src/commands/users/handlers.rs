@@ -1,5 +1,7 @@
 use gitee_rs::GiteeClient;
 use anyhow::Result;
+use crate::commands::keys::handle_ssh_keys;
+use crate::errors::report_error;
 use super::UserCommands;
 
 pub async fn handle_users(client: &GiteeClient, cmd: &UserCommands) -> Result<()> {
@@ -12,7 +14,7 @@ pub async fn handle_users(client: &GiteeClient, cmd: &UserCommands) -> Result<()
                         println!("User: {} ({})", user.name.as_deref().unwrap_or("N/A"), user.login);
                         println!("Repos: {}, Followers: {}", user.public_repos, user.followers);
                     }
-                    Err(e) => eprintln!("Error fetching user info: {}", e),
+                    Err(e) => report_error("Error fetching user info", &e),
                 }
             } else {
                 println!("Fetching your profile...");
@@ -21,7 +23,7 @@ pub async fn handle_users(client: &GiteeClient, cmd: &UserCommands) -> Result<()
                         println!("Authenticated as: {} ({})", user.name.as_deref().unwrap_or("N/A"), user.login);
                         println!("Repos: {}, Followers: {}", user.public_repos, user.followers);
                     }
-                    Err(e) => eprintln!("Error fetching authenticated user: {}", e),
+                    Err(e) => report_error("Error fetching authenticated user", &e),
                 }
             }
         }
@@ -38,9 +40,10 @@ pub async fn handle_users(client: &GiteeClient, cmd: &UserCommands) -> Result<()
                         }
                     }
                 }
-                Err(e) => eprintln!("Error searching users: {}", e),
+                Err(e) => report_error("Error searching users", &e),
             }
         }
+        UserCommands::Keys { cmd } => handle_ssh_keys(client, cmd).await?,
     }
     Ok(())
 }
@@ -15,4 +15,9 @@ pub enum UserCommands {
         /// Query to search for
         query: String,
     },
+    /// Manage your SSH public keys
+    Keys {
+        #[command(subcommand)]
+        cmd: crate::commands::keys::SshKeyCommands,
+    },
 }
@@ -0,0 +1,13 @@
+use gitee_rs::GiteeError;
+
+/// Print an API error along with a hint for the most common failure causes
+pub fn report_error(context: &str, err: &GiteeError) {
+    eprintln!("{}: {}", context, err);
+    if err.is_rate_limited() {
+        eprintln!("  Hint: the Gitee API rate limit is exhausted, please try again later.");
+    } else if err.is_auth_error() {
+        eprintln!("  Hint: check that GITEE_TOKEN is valid and has access to this resource.");
+    } else if err.is_not_found() {
+        eprintln!("  Hint: the resource does not exist or is not visible to your token.");
+    }
+}
@@ -1,4 +1,6 @@
 mod commands;
+mod errors;
+mod prompt;
 mod l10n;
 
 use clap::Parser;
@@ -15,6 +17,11 @@ use crate::commands::files::handle_files;
 use crate::commands::releases::handle_releases;
 use crate::commands::labels::handle_labels;
 use crate::commands::wikis::handle_wikis;
+use crate::commands::branches::handle_branches;
+use crate::commands::commits::handle_commits;
+use crate::commands::tags::handle_tags;
+use crate::commands::orgs::handle_orgs;
+use crate::commands::enterprises::handle_enterprises;
 
 #[derive(Parser)]
 #[command(author, version, about = "Gitee CLI - Manage issues, PRs, repos and more", long_about = None)]
@@ -88,7 +95,32 @@ enum Commands {
     Wiki {
         #[command(subcommand)]
         cmd: crate::commands::wikis::WikiCommands,
-    }
+    },
+    /// Manage branches (分支管理)
+    Branch {
+        #[command(subcommand)]
+        cmd: crate::commands::branches::BranchCommands,
+    },
+    /// Inspect commits and compare refs (提交历史)
+    Commit {
+        #[command(subcommand)]
+        cmd: crate::commands::commits::CommitCommands,
+    },
+    /// Manage git tags (Git 标签管理)
+    Tag {
+        #[command(subcommand)]
+        cmd: crate::commands::tags::TagCommands,
+    },
+    /// Manage organizations (组织管理)
+    Org {
+        #[command(subcommand)]
+        cmd: crate::commands::orgs::OrgCommands,
+    },
+    /// Enterprise workspace reporting (企业管理)
+    Enterprise {
+        #[command(subcommand)]
+        cmd: crate::commands::enterprises::EnterpriseCommands,
+    },
 }
 
 #[tokio::main]
@@ -111,6 +143,11 @@ async fn main() -> Result<()> {
         Commands::Files { cmd } => handle_files(&client, cmd).await?,
         Commands::Releases { cmd } => handle_releases(&client, cmd).await?,
         Commands::Wiki { cmd } => handle_wikis(&client, cmd).await?,
+        Commands::Branch { cmd } => handle_branches(&client, cmd).await?,
+        Commands::Commit { cmd } => handle_commits(&client, cmd).await?,
+        Commands::Tag { cmd } => handle_tags(&client, cmd).await?,
+        Commands::Org { cmd } => handle_orgs(&client, cmd).await?,
+        Commands::Enterprise { cmd } => handle_enterprises(&client, cmd).await?,
     }
 
     Ok(())
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use std::io::{self, BufRead, Write};
+
+/// Ask a yes/no question on stdin; anything but `y`/`yes` declines
+pub fn confirm(question: &str) -> Result<bool> {
+    let answer = ask(&format!("{} [y/N] ", question))?;
+    Ok(matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Ask the user to type `expected` exactly, for operations that are hard to undo
+pub fn confirm_typed(question: &str, expected: &str) -> Result<bool> {
+    let answer = ask(&format!("{}\nType '{}' to confirm: ", question, expected))?;
+    Ok(answer == expected)
+}
+
+fn ask(prompt: &str) -> Result<String> {
+    print!("{}", prompt);
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
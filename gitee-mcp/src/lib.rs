@@ -1,9 +1,62 @@
+use gitee_rs::GiteeError;
 use serde::{Serialize, Deserialize};
-use serde_json::Value;
+use serde_json::{json, Value};
+use std::fmt;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Tool {
     pub name: String,
     pub description: String,
     pub input_schema: Value,
+    /// Behaviour hints for clients, e.g. `{"destructiveHint": true}` on tools that delete data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Value>,
+}
+
+/// Error returned by a tool handler, carrying structured API details when available
+#[derive(Serialize, Debug, Clone)]
+pub struct ToolError {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl ToolError {
+    /// Wrap a gitee-rs error with a short description of the failed operation
+    pub fn gitee(context: &str, err: &GiteeError) -> Self {
+        let data = err.http().map(|http| {
+            json!({
+                "status": http.status.as_u16(),
+                "method": http.method,
+                "path": http.path,
+                "message": http.message,
+                "errors": http.errors,
+                "not_found": err.is_not_found(),
+                "rate_limited": err.is_rate_limited(),
+                "auth_error": err.is_auth_error(),
+            })
+        });
+        ToolError {
+            message: format!("{}: {}", context, err),
+            data,
+        }
+    }
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<&str> for ToolError {
+    fn from(message: &str) -> Self {
+        ToolError { message: message.to_string(), data: None }
+    }
+}
+
+impl From<String> for ToolError {
+    fn from(message: String) -> Self {
+        ToolError { message, data: None }
+    }
 }
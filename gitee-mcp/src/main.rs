@@ -5,7 +5,7 @@ mod tools;
 mod server;
 mod l10n;
 
-use gitee_mcp::Tool;
+use gitee_mcp::{Tool, ToolError};
 use gitee_rs::GiteeClient;
 use clap::Parser;
 use std::fs;
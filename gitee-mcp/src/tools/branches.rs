@@ -0,0 +1,243 @@
+use gitee_rs::GiteeClient;
+use serde_json::{json, Value};
+use crate::{Tool, ToolError};
+
+pub fn get_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "list_repo_branches".to_string(),
+            description: "List branches of a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_repo_branch".to_string(),
+            description: "Get a branch and its head commit".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "branch": { "type": "string" }
+                },
+                "required": ["owner", "repo", "branch"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "create_branch".to_string(),
+            description: "Create a branch from a branch, tag or commit sha".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "branch_name": { "type": "string" },
+                    "refs": { "type": "string", "description": "Branch, tag or commit to start from" }
+                },
+                "required": ["owner", "repo", "branch_name", "refs"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_branch".to_string(),
+            description: "Delete a branch".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "branch": { "type": "string" }
+                },
+                "required": ["owner", "repo", "branch"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+        Tool {
+            name: "protect_branch".to_string(),
+            description: "Set a branch as protected".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "branch": { "type": "string" }
+                },
+                "required": ["owner", "repo", "branch"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "unprotect_branch".to_string(),
+            description: "Remove protection from a branch".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "branch": { "type": "string" }
+                },
+                "required": ["owner", "repo", "branch"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "create_branch_protection_rule".to_string(),
+            description: "Create a protection rule for branches matching a wildcard".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "wildcard": { "type": "string", "description": "Branch wildcard, e.g. release/*" },
+                    "pusher": { "type": "string", "description": "admin, none or comma-separated usernames" },
+                    "merger": { "type": "string", "description": "admin, none or comma-separated usernames" }
+                },
+                "required": ["owner", "repo", "wildcard"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "update_branch_protection_rule".to_string(),
+            description: "Update a wildcard branch protection rule. Omitted pusher/merger keep their current value".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "wildcard": { "type": "string" },
+                    "new_wildcard": { "type": "string" },
+                    "pusher": { "type": "string", "description": "admin, none or comma-separated usernames" },
+                    "merger": { "type": "string", "description": "admin, none or comma-separated usernames" }
+                },
+                "required": ["owner", "repo", "wildcard"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_branch_protection_rule".to_string(),
+            description: "Delete a wildcard branch protection rule".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "wildcard": { "type": "string" }
+                },
+                "required": ["owner", "repo", "wildcard"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+    ]
+}
+
+pub async fn handle_list_branches(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+
+    match client.list_branches(owner, repo).await {
+        Ok(branches) => Ok(json!({ "branches": branches })),
+        Err(e) => Err(ToolError::gitee("Failed to list branches", &e)),
+    }
+}
+
+pub async fn handle_get_branch(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let branch = args.get("branch").and_then(|v| v.as_str()).ok_or("Missing 'branch' parameter")?;
+
+    match client.get_branch(owner, repo, branch).await {
+        Ok(branch) => Ok(json!({ "branch": branch })),
+        Err(e) => Err(ToolError::gitee("Failed to get branch", &e)),
+    }
+}
+
+pub async fn handle_create_branch(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let branch_name = args.get("branch_name").and_then(|v| v.as_str()).ok_or("Missing 'branch_name' parameter")?;
+    let refs = args.get("refs").and_then(|v| v.as_str()).ok_or("Missing 'refs' parameter")?;
+
+    match client.create_branch(owner, repo, branch_name, refs).await {
+        Ok(branch) => Ok(json!({ "branch": branch })),
+        Err(e) => Err(ToolError::gitee("Failed to create branch", &e)),
+    }
+}
+
+pub async fn handle_delete_branch(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let branch = args.get("branch").and_then(|v| v.as_str()).ok_or("Missing 'branch' parameter")?;
+
+    match client.delete_branch(owner, repo, branch).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete branch", &e)),
+    }
+}
+
+pub async fn handle_protect_branch(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let branch = args.get("branch").and_then(|v| v.as_str()).ok_or("Missing 'branch' parameter")?;
+
+    match client.protect_branch(owner, repo, branch).await {
+        Ok(branch) => Ok(json!({ "branch": branch })),
+        Err(e) => Err(ToolError::gitee("Failed to protect branch", &e)),
+    }
+}
+
+pub async fn handle_unprotect_branch(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let branch = args.get("branch").and_then(|v| v.as_str()).ok_or("Missing 'branch' parameter")?;
+
+    match client.unprotect_branch(owner, repo, branch).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to unprotect branch", &e)),
+    }
+}
+
+pub async fn handle_create_protection_rule(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let wildcard = args.get("wildcard").and_then(|v| v.as_str()).ok_or("Missing 'wildcard' parameter")?;
+    let pusher = args.get("pusher").and_then(|v| v.as_str());
+    let merger = args.get("merger").and_then(|v| v.as_str());
+
+    match client.create_protection_rule(owner, repo, wildcard, pusher, merger).await {
+        Ok(rule) => Ok(json!({ "rule": rule })),
+        Err(e) => Err(ToolError::gitee("Failed to create protection rule", &e)),
+    }
+}
+
+pub async fn handle_update_protection_rule(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let wildcard = args.get("wildcard").and_then(|v| v.as_str()).ok_or("Missing 'wildcard' parameter")?;
+    let new_wildcard = args.get("new_wildcard").and_then(|v| v.as_str());
+    let pusher = args.get("pusher").and_then(|v| v.as_str());
+    let merger = args.get("merger").and_then(|v| v.as_str());
+
+    match client.update_protection_rule(owner, repo, wildcard, new_wildcard, pusher, merger).await {
+        Ok(rule) => Ok(json!({ "rule": rule })),
+        Err(e) => Err(ToolError::gitee("Failed to update protection rule", &e)),
+    }
+}
+
+pub async fn handle_delete_protection_rule(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let wildcard = args.get("wildcard").and_then(|v| v.as_str()).ok_or("Missing 'wildcard' parameter")?;
+
+    match client.delete_protection_rule(owner, repo, wildcard).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete protection rule", &e)),
+    }
+}
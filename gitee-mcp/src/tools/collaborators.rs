@@ -0,0 +1,136 @@
+use gitee_rs::{GiteeClient, Permission};
+use serde_json::{json, Value};
+use crate::{Tool, ToolError};
+
+pub fn get_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "list_repo_collaborators".to_string(),
+            description: "List collaborators of a repository with their permissions".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "check_repo_collaborator".to_string(),
+            description: "Check whether a user is a collaborator on a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "username": { "type": "string" }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "add_repo_collaborator".to_string(),
+            description: "Add a collaborator to a repository, or change the permission of an existing one".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "username": { "type": "string" },
+                    "permission": { "type": "string", "enum": ["pull", "push", "admin"], "description": "Defaults to push" }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "remove_repo_collaborator".to_string(),
+            description: "Remove a collaborator from a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "username": { "type": "string" }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+        Tool {
+            name: "get_repo_collaborator_permission".to_string(),
+            description: "Get a user's permission on a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "username": { "type": "string" }
+                },
+                "required": ["owner", "repo", "username"]
+            }),
+            annotations: None,
+        },
+    ]
+}
+
+pub async fn handle_list_collaborators(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+
+    match client.list_collaborators(owner, repo).await {
+        Ok(collaborators) => Ok(json!({ "collaborators": collaborators })),
+        Err(e) => Err(ToolError::gitee("Failed to list collaborators", &e)),
+    }
+}
+
+pub async fn handle_check_collaborator(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let username = args.get("username").and_then(|v| v.as_str()).ok_or("Missing 'username' parameter")?;
+
+    match client.is_collaborator(owner, repo, username).await {
+        Ok(is_collaborator) => Ok(json!({ "username": username, "is_collaborator": is_collaborator })),
+        Err(e) => Err(ToolError::gitee("Failed to check collaborator", &e)),
+    }
+}
+
+pub async fn handle_add_collaborator(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let username = args.get("username").and_then(|v| v.as_str()).ok_or("Missing 'username' parameter")?;
+    let permission = match args.get("permission").and_then(|v| v.as_str()) {
+        Some(name) => Permission::parse(name).ok_or(format!("Unsupported permission '{}'", name))?,
+        None => Permission::Push,
+    };
+
+    match client.add_collaborator(owner, repo, username, permission).await {
+        Ok(collaborator) => Ok(json!({ "collaborator": collaborator })),
+        Err(e) => Err(ToolError::gitee("Failed to add collaborator", &e)),
+    }
+}
+
+pub async fn handle_remove_collaborator(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let username = args.get("username").and_then(|v| v.as_str()).ok_or("Missing 'username' parameter")?;
+
+    match client.remove_collaborator(owner, repo, username).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to remove collaborator", &e)),
+    }
+}
+
+pub async fn handle_get_collaborator_permission(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let username = args.get("username").and_then(|v| v.as_str()).ok_or("Missing 'username' parameter")?;
+
+    match client.get_collaborator_permission(owner, repo, username).await {
+        Ok(permission) => Ok(json!({ "permission": permission })),
+        Err(e) => Err(ToolError::gitee("Failed to get collaborator permission", &e)),
+    }
+}
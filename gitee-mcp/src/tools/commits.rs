@@ -0,0 +1,100 @@
+use gitee_rs::{commits::CommitListOptions, GiteeClient};
+use serde_json::{json, Value};
+use crate::{Tool, ToolError};
+
+pub fn get_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "list_repo_commits".to_string(),
+            description: "List commits of a repository, optionally filtered by ref, path, author or date range".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "sha": { "type": "string", "description": "Branch name or commit sha to start from" },
+                    "path": { "type": "string", "description": "Only commits touching this path" },
+                    "author": { "type": "string" },
+                    "since": { "type": "string", "description": "ISO 8601 date" },
+                    "until": { "type": "string", "description": "ISO 8601 date" },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_repo_commit".to_string(),
+            description: "Get a single commit with its stats and changed files".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "sha": { "type": "string", "description": "Commit sha, branch or tag" }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "compare_repo_commits".to_string(),
+            description: "Compare two branches, tags or commits and list the commits and files in between".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "base": { "type": "string" },
+                    "head": { "type": "string" }
+                },
+                "required": ["owner", "repo", "base", "head"]
+            }),
+            annotations: None,
+        },
+    ]
+}
+
+pub async fn handle_list_commits(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+
+    let options = CommitListOptions {
+        sha: args.get("sha").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        path: args.get("path").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        author: args.get("author").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        since: args.get("since").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        until: args.get("until").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        page: args.get("page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        per_page: args.get("per_page").and_then(|v| v.as_i64()).map(|v| v as i32),
+    };
+
+    match client.list_commits(owner, repo, Some(options)).await {
+        Ok(commits) => Ok(json!({ "commits": commits })),
+        Err(e) => Err(ToolError::gitee("Failed to list commits", &e)),
+    }
+}
+
+pub async fn handle_get_commit(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let sha = args.get("sha").and_then(|v| v.as_str()).ok_or("Missing 'sha' parameter")?;
+
+    match client.get_commit(owner, repo, sha).await {
+        Ok(commit) => Ok(json!({ "commit": commit })),
+        Err(e) => Err(ToolError::gitee("Failed to get commit", &e)),
+    }
+}
+
+pub async fn handle_compare_commits(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let base = args.get("base").and_then(|v| v.as_str()).ok_or("Missing 'base' parameter")?;
+    let head = args.get("head").and_then(|v| v.as_str()).ok_or("Missing 'head' parameter")?;
+
+    match client.compare_commits(owner, repo, base, head).await {
+        Ok(compare) => Ok(json!({ "compare": compare })),
+        Err(e) => Err(ToolError::gitee("Failed to compare commits", &e)),
+    }
+}
@@ -11,6 +11,11 @@ pub fn get_tools_list() -> Vec<Tool> {
     tools.extend(crate::tools::notifications::get_tool_definitions());
     tools.extend(crate::tools::files::get_tool_definitions());
     tools.extend(crate::tools::wikis::get_tool_definitions());
+    tools.extend(crate::tools::branches::get_tool_definitions());
+    tools.extend(crate::tools::commits::get_tool_definitions());
+    tools.extend(crate::tools::tags::get_tool_definitions());
+    tools.extend(crate::tools::collaborators::get_tool_definitions());
+    tools.extend(crate::tools::enterprises::get_tool_definitions());
     
     tools
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use serde_json::{Value};
+use crate::ToolError;
 use gitee_rs::GiteeClient;
 use crate::tools::issues::*;
 use crate::tools::pulls::*;
@@ -8,79 +9,148 @@ use crate::tools::users::*;
 use crate::tools::notifications::*;
 use crate::tools::files::*;
 use crate::tools::wikis::*;
+use crate::tools::branches::*;
+use crate::tools::commits::*;
+use crate::tools::tags::*;
+use crate::tools::collaborators::*;
+use crate::tools::enterprises::*;
 
 pub async fn dispatch_tool_call(
     client: &GiteeClient,
     tool_name: &str,
     arguments: &Value,
-) -> Result<Value, String> {
+) -> Result<Value, ToolError> {
     match tool_name {
         // Issues
-        "list_repo_issues" => handle_list_issues(client, arguments).await.map_err(|e| e.to_string()),
-        "get_repo_issue_detail" => handle_get_issue_detail(client, arguments).await.map_err(|e| e.to_string()),
-        "create_issue" => handle_create_issue(client, arguments).await.map_err(|e| e.to_string()),
-        "update_issue" => handle_update_issue(client, arguments).await.map_err(|e| e.to_string()),
-        "close_issue" => handle_close_issue(client, arguments).await.map_err(|e| e.to_string()),
-        "comment_issue" => handle_comment_issue(client, arguments).await.map_err(|e| e.to_string()),
-        "list_issue_comments" => handle_list_issue_comments(client, arguments).await.map_err(|e| e.to_string()),
-        "list_repo_milestones" => handle_list_repo_milestones(client, arguments).await.map_err(|e| e.to_string()),
-        "create_milestone" => handle_create_milestone(client, arguments).await.map_err(|e| e.to_string()),
-        "get_milestone" => handle_get_milestone(client, arguments).await.map_err(|e| e.to_string()),
-        "update_milestone" => handle_update_milestone(client, arguments).await.map_err(|e| e.to_string()),
-        "delete_milestone" => handle_delete_milestone(client, arguments).await.map_err(|e| e.to_string()),
+        "list_repo_issues" => handle_list_issues(client, arguments).await,
+        "get_repo_issue_detail" => handle_get_issue_detail(client, arguments).await,
+        "create_issue" => handle_create_issue(client, arguments).await,
+        "update_issue" => handle_update_issue(client, arguments).await,
+        "close_issue" => handle_close_issue(client, arguments).await,
+        "comment_issue" => handle_comment_issue(client, arguments).await,
+        "list_issue_comments" => handle_list_issue_comments(client, arguments).await,
+        "list_repo_milestones" => handle_list_repo_milestones(client, arguments).await,
+        "create_milestone" => handle_create_milestone(client, arguments).await,
+        "get_milestone" => handle_get_milestone(client, arguments).await,
+        "update_milestone" => handle_update_milestone(client, arguments).await,
+        "delete_milestone" => handle_delete_milestone(client, arguments).await,
 
         // ... Pull Requests ...
-        "list_repo_pulls" => handle_list_pulls(client, arguments).await.map_err(|e| e.to_string()),
-        "get_pull_detail" => handle_get_pull_detail(client, arguments).await.map_err(|e| e.to_string()),
-        "create_pull" => handle_create_pull(client, arguments).await.map_err(|e| e.to_string()),
-        "update_pull" => handle_update_pull(client, arguments).await.map_err(|e| e.to_string()),
-        "merge_pull" => handle_merge_pull(client, arguments).await.map_err(|e| e.to_string()),
-        "comment_pull" => handle_comment_pull(client, arguments).await.map_err(|e| e.to_string()),
-        "list_pull_comments" => handle_list_pull_comments(client, arguments).await.map_err(|e| e.to_string()),
-        "get_diff_files" => handle_get_diff_files(client, arguments).await.map_err(|e| e.to_string()),
+        "list_repo_pulls" => handle_list_pulls(client, arguments).await,
+        "get_pull_detail" => handle_get_pull_detail(client, arguments).await,
+        "create_pull" => handle_create_pull(client, arguments).await,
+        "update_pull" => handle_update_pull(client, arguments).await,
+        "merge_pull" => handle_merge_pull(client, arguments).await,
+        "comment_pull" => handle_comment_pull(client, arguments).await,
+        "list_pull_comments" => handle_list_pull_comments(client, arguments).await,
+        "get_diff_files" => handle_get_diff_files(client, arguments).await,
+        "list_pull_commits" => handle_list_pull_commits(client, arguments).await,
+        "get_pull_diff" => handle_get_pull_diff(client, arguments).await,
+        "create_pull_review_comment" => handle_create_pull_review_comment(client, arguments).await,
+        "list_pull_review_comments" => handle_list_pull_review_comments(client, arguments).await,
+        "reply_pull_review_comment" => handle_reply_pull_review_comment(client, arguments).await,
+        "update_pull_comment" => handle_update_pull_comment(client, arguments).await,
+        "delete_pull_comment" => handle_delete_pull_comment(client, arguments).await,
+        "add_pull_reviewers" => handle_add_pull_reviewers(client, arguments).await,
+        "remove_pull_reviewers" => handle_remove_pull_reviewers(client, arguments).await,
+        "add_pull_testers" => handle_add_pull_testers(client, arguments).await,
+        "remove_pull_testers" => handle_remove_pull_testers(client, arguments).await,
+        "approve_pull_review" => handle_approve_pull_review(client, arguments).await,
+        "pass_pull_test" => handle_pass_pull_test(client, arguments).await,
+        "reset_pull_review" => handle_reset_pull_review(client, arguments).await,
+        "reset_pull_test" => handle_reset_pull_test(client, arguments).await,
+        "get_pull_review_status" => handle_get_pull_review_status(client, arguments).await,
 
         // Repositories
-        "list_user_repos" => handle_list_user_repos(client).await.map_err(|e| e.to_string()),
-        "get_repo" => handle_get_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "create_user_repo" => handle_create_user_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "create_org_repo" => handle_create_org_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "create_enterprise_repo" => handle_create_enterprise_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "delete_repo" => handle_delete_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "fork_repository" => handle_fork_repository(client, arguments).await.map_err(|e| e.to_string()),
-        "search_open_source_repositories" => handle_search_repositories(client, arguments).await.map_err(|e| e.to_string()),
-        "create_release" => handle_create_release(client, arguments).await.map_err(|e| e.to_string()),
-        "list_releases" => handle_list_releases(client, arguments).await.map_err(|e| e.to_string()),
-        "star_repo" => handle_star_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "unstar_repo" => handle_unstar_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "watch_repo" => handle_watch_repo(client, arguments).await.map_err(|e| e.to_string()),
-        "unwatch_repo" => handle_unwatch_repo(client, arguments).await.map_err(|e| e.to_string()),
+        "list_user_repos" => handle_list_user_repos(client).await,
+        "get_repo" => handle_get_repo(client, arguments).await,
+        "create_user_repo" => handle_create_user_repo(client, arguments).await,
+        "create_org_repo" => handle_create_org_repo(client, arguments).await,
+        "create_enterprise_repo" => handle_create_enterprise_repo(client, arguments).await,
+        "delete_repo" => handle_delete_repo(client, arguments).await,
+        "fork_repository" => handle_fork_repository(client, arguments).await,
+        "search_open_source_repositories" => handle_search_repositories(client, arguments).await,
+        "create_release" => handle_create_release(client, arguments).await,
+        "list_releases" => handle_list_releases(client, arguments).await,
+        "get_release" => handle_get_release(client, arguments).await,
+        "get_latest_release" => handle_get_latest_release(client, arguments).await,
+        "get_release_by_tag" => handle_get_release_by_tag(client, arguments).await,
+        "update_release" => handle_update_release(client, arguments).await,
+        "delete_release" => handle_delete_release(client, arguments).await,
+        "list_release_assets" => handle_list_release_assets(client, arguments).await,
+        "upload_release_asset" => handle_upload_release_asset(client, arguments).await,
+        "download_release_asset" => handle_download_release_asset(client, arguments).await,
+        "delete_release_asset" => handle_delete_release_asset(client, arguments).await,
+        "star_repo" => handle_star_repo(client, arguments).await,
+        "unstar_repo" => handle_unstar_repo(client, arguments).await,
+        "watch_repo" => handle_watch_repo(client, arguments).await,
+        "unwatch_repo" => handle_unwatch_repo(client, arguments).await,
 
         // Users
-        "get_user_info" => handle_get_authenticated_user(client).await.map_err(|e| e.to_string()),
-        "get_user_detail" => handle_get_user_info(client, arguments).await.map_err(|e| e.to_string()),
-        "search_users" => handle_search_users(client, arguments).await.map_err(|e| e.to_string()),
+        "get_user_info" => handle_get_authenticated_user(client).await,
+        "get_user_detail" => handle_get_user_info(client, arguments).await,
+        "search_users" => handle_search_users(client, arguments).await,
 
         // Notifications
-        "list_user_notifications" => handle_list_notifications(client).await.map_err(|e| e.to_string()),
+        "list_user_notifications" => handle_list_notifications(client).await,
 
         // Files
-        "get_file_content" => handle_get_file_content(client, arguments).await.map_err(|e| e.to_string()),
-        "list_repo_files" => handle_list_repo_files(client, arguments).await.map_err(|e| e.to_string()),
-        "search_files_by_content" => handle_search_files_by_content(client, arguments).await.map_err(|e| e.to_string()),
+        "get_file_content" => handle_get_file_content(client, arguments).await,
+        "get_repo_readme" => handle_get_repo_readme(client, arguments).await,
+        "render_markdown" => handle_render_markdown(client, arguments).await,
+        "list_repo_files" => handle_list_repo_files(client, arguments).await,
+        "search_files_by_content" => handle_search_files_by_content(client, arguments).await,
+        "get_repo_tree" => handle_get_repo_tree(client, arguments).await,
+        "get_repo_blob" => handle_get_repo_blob(client, arguments).await,
+        "create_file" => handle_create_file(client, arguments).await,
+        "update_file" => handle_update_file(client, arguments).await,
+        "delete_file" => handle_delete_file(client, arguments).await,
 
         // Labels
-        "list_labels" => handle_list_labels(client, arguments).await.map_err(|e| e.to_string()),
-        "create_label" => handle_create_label(client, arguments).await.map_err(|e| e.to_string()),
-        "update_label" => handle_update_label(client, arguments).await.map_err(|e| e.to_string()),
-        "delete_label" => handle_delete_label(client, arguments).await.map_err(|e| e.to_string()),
+        "list_labels" => handle_list_labels(client, arguments).await,
+        "create_label" => handle_create_label(client, arguments).await,
+        "update_label" => handle_update_label(client, arguments).await,
+        "delete_label" => handle_delete_label(client, arguments).await,
 
         // Wikis
-        "list_repo_wikis" => handle_list_repo_wikis(client, arguments).await.map_err(|e| e.to_string()),
-        "get_repo_wiki" => handle_get_repo_wiki(client, arguments).await.map_err(|e| e.to_string()),
-        "create_repo_wiki" => handle_create_repo_wiki(client, arguments).await.map_err(|e| e.to_string()),
-        "update_repo_wiki" => handle_update_repo_wiki(client, arguments).await.map_err(|e| e.to_string()),
-        "delete_repo_wiki" => handle_delete_repo_wiki(client, arguments).await.map_err(|e| e.to_string()),
+        "list_repo_wikis" => handle_list_repo_wikis(client, arguments).await,
+        "get_repo_wiki" => handle_get_repo_wiki(client, arguments).await,
+        "create_repo_wiki" => handle_create_repo_wiki(client, arguments).await,
+        "update_repo_wiki" => handle_update_repo_wiki(client, arguments).await,
+        "delete_repo_wiki" => handle_delete_repo_wiki(client, arguments).await,
 
-        _ => Err(format!("Tool not found: {}", tool_name)),
+        // Branches
+        "list_repo_branches" => handle_list_branches(client, arguments).await,
+        "get_repo_branch" => handle_get_branch(client, arguments).await,
+        "create_branch" => handle_create_branch(client, arguments).await,
+        "delete_branch" => handle_delete_branch(client, arguments).await,
+        "protect_branch" => handle_protect_branch(client, arguments).await,
+        "unprotect_branch" => handle_unprotect_branch(client, arguments).await,
+        "create_branch_protection_rule" => handle_create_protection_rule(client, arguments).await,
+        "update_branch_protection_rule" => handle_update_protection_rule(client, arguments).await,
+        "delete_branch_protection_rule" => handle_delete_protection_rule(client, arguments).await,
+
+        // Commits
+        "list_repo_commits" => handle_list_commits(client, arguments).await,
+        "get_repo_commit" => handle_get_commit(client, arguments).await,
+        "compare_repo_commits" => handle_compare_commits(client, arguments).await,
+
+        // Tags
+        "list_repo_tags" => handle_list_tags(client, arguments).await,
+        "create_tag" => handle_create_tag(client, arguments).await,
+        "delete_tag" => handle_delete_tag(client, arguments).await,
+        "list_repo_collaborators" => handle_list_collaborators(client, arguments).await,
+        "check_repo_collaborator" => handle_check_collaborator(client, arguments).await,
+        "add_repo_collaborator" => handle_add_collaborator(client, arguments).await,
+        "remove_repo_collaborator" => handle_remove_collaborator(client, arguments).await,
+        "get_repo_collaborator_permission" => handle_get_collaborator_permission(client, arguments).await,
+        "get_enterprise" => handle_get_enterprise(client, arguments).await,
+        "list_enterprise_members" => handle_list_enterprise_members(client, arguments).await,
+        "list_enterprise_repos" => handle_list_enterprise_repos(client, arguments).await,
+        "list_enterprise_issues" => handle_list_enterprise_issues(client, arguments).await,
+        "list_enterprise_pulls" => handle_list_enterprise_pulls(client, arguments).await,
+        "list_enterprise_labels" => handle_list_enterprise_labels(client, arguments).await,
+
+        _ => Err(format!("Tool not found: {}", tool_name).into()),
     }
 }
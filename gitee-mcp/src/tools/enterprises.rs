@@ -0,0 +1,193 @@
+use gitee_rs::{enterprises::{EnterpriseIssueListOptions, EnterpriseMemberListOptions, EnterprisePullListOptions, EnterpriseRepoListOptions}, EnterpriseIssueState, GiteeClient};
+use serde_json::{json, Value};
+use crate::{Tool, ToolError};
+
+pub fn get_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "get_enterprise".to_string(),
+            description: "Get information about an enterprise".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_enterprise_members".to_string(),
+            description: "List members of an enterprise with their roles".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" },
+                    "role": { "type": "string", "enum": ["all", "admin", "member"] },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_enterprise_repos".to_string(),
+            description: "List repositories of an enterprise".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" },
+                    "type": { "type": "string", "enum": ["all", "public", "internal", "private"] },
+                    "search": { "type": "string" },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_enterprise_issues".to_string(),
+            description: "List issues across all repositories of an enterprise, including issue type and workflow state".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" },
+                    "state": { "type": "string", "enum": ["open", "progressing", "closed", "rejected", "all"] },
+                    "labels": { "type": "string", "description": "Comma separated label names" },
+                    "assignee": { "type": "string" },
+                    "creator": { "type": "string" },
+                    "milestone": { "type": "string" },
+                    "since": { "type": "string" },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_enterprise_pulls".to_string(),
+            description: "List pull requests across all repositories of an enterprise".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" },
+                    "repo": { "type": "string", "description": "Only pull requests of this repository path" },
+                    "state": { "type": "string", "enum": ["open", "closed", "merged", "all"] },
+                    "labels": { "type": "string" },
+                    "page": { "type": "integer" },
+                    "per_page": { "type": "integer" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_enterprise_labels".to_string(),
+            description: "List labels shared across an enterprise".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "enterprise": { "type": "string", "description": "Enterprise path" }
+                },
+                "required": ["enterprise"]
+            }),
+            annotations: None,
+        },
+    ]
+}
+
+pub async fn handle_get_enterprise(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+
+    match client.get_enterprise(enterprise).await {
+        Ok(enterprise) => Ok(json!({ "enterprise": enterprise })),
+        Err(e) => Err(ToolError::gitee("Failed to get enterprise", &e)),
+    }
+}
+
+pub async fn handle_list_enterprise_members(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+
+    let options = EnterpriseMemberListOptions {
+        role: args.get("role").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        page: args.get("page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        per_page: args.get("per_page").and_then(|v| v.as_i64()).map(|v| v as i32),
+    };
+
+    match client.list_enterprise_members(enterprise, Some(options)).await {
+        Ok(members) => Ok(json!({ "members": members })),
+        Err(e) => Err(ToolError::gitee("Failed to list enterprise members", &e)),
+    }
+}
+
+pub async fn handle_list_enterprise_repos(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+
+    let options = EnterpriseRepoListOptions {
+        search: args.get("search").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        repo_type: args.get("type").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        direct: None,
+        page: args.get("page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        per_page: args.get("per_page").and_then(|v| v.as_i64()).map(|v| v as i32),
+    };
+
+    match client.list_enterprise_repos(enterprise, Some(options)).await {
+        Ok(repos) => Ok(json!({ "repositories": repos })),
+        Err(e) => Err(ToolError::gitee("Failed to list enterprise repositories", &e)),
+    }
+}
+
+pub async fn handle_list_enterprise_issues(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+    let state = match args.get("state").and_then(|v| v.as_str()) {
+        Some(name) => Some(EnterpriseIssueState::parse(name).ok_or(format!("Unsupported state '{}'", name))?),
+        None => None,
+    };
+
+    let options = EnterpriseIssueListOptions {
+        state,
+        labels: args.get("labels").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        assignee: args.get("assignee").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        creator: args.get("creator").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        milestone: args.get("milestone").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        since: args.get("since").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        page: args.get("page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        per_page: args.get("per_page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        ..Default::default()
+    };
+
+    match client.list_enterprise_issues(enterprise, Some(options)).await {
+        Ok(issues) => Ok(json!({ "issues": issues })),
+        Err(e) => Err(ToolError::gitee("Failed to list enterprise issues", &e)),
+    }
+}
+
+pub async fn handle_list_enterprise_pulls(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+
+    let options = EnterprisePullListOptions {
+        repo: args.get("repo").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        state: args.get("state").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        labels: args.get("labels").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        page: args.get("page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        per_page: args.get("per_page").and_then(|v| v.as_i64()).map(|v| v as i32),
+        ..Default::default()
+    };
+
+    match client.list_enterprise_pulls(enterprise, Some(options)).await {
+        Ok(pulls) => Ok(json!({ "pull_requests": pulls })),
+        Err(e) => Err(ToolError::gitee("Failed to list enterprise pull requests", &e)),
+    }
+}
+
+pub async fn handle_list_enterprise_labels(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
+
+    match client.list_enterprise_labels(enterprise).await {
+        Ok(labels) => Ok(json!({ "labels": labels })),
+        Err(e) => Err(ToolError::gitee("Failed to list enterprise labels", &e)),
+    }
+}
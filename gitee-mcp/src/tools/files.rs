@@ -1,12 +1,12 @@
-use gitee_rs::GiteeClient;
+use gitee_rs::{files::{CommitIdentity, FileWriteOptions}, GiteeClient, GiteeError};
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
         Tool {
             name: "get_file_content".to_string(),
-            description: "Get the content of a file in a repository".to_string(),
+            description: "Get the content of a file in a repository. The decoded text is returned in `text`; for binary or undecodable files it is null and `decode_error` says why".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
@@ -17,6 +17,33 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "path"]
             }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_repo_readme".to_string(),
+            description: "Get the README of a repository without knowing its path. Returns its `path`, `html_url` and decoded `text`".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "ref": { "type": "string", "description": "Branch, tag or commit (defaults to the default branch)" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "render_markdown".to_string(),
+            description: "Render Markdown to HTML the way Gitee displays it".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "text": { "type": "string", "description": "Markdown source" }
+                },
+                "required": ["text"]
+            }),
+            annotations: None,
         },
         Tool {
             name: "list_repo_files".to_string(),
@@ -31,6 +58,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "search_files_by_content".to_string(),
@@ -46,23 +74,149 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["q"]
             }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_repo_tree".to_string(),
+            description: "Get the git tree of a branch, tag or commit. With recursive=true every nested file is listed in one call".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "sha": { "type": "string", "description": "Branch, tag or commit sha" },
+                    "recursive": { "type": "boolean", "description": "List nested entries (default true)" }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_repo_blob".to_string(),
+            description: "Get a git blob by sha. The decoded text is returned in `text`; for binary or undecodable blobs it is null and `decode_error` says why".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "sha": { "type": "string" }
+                },
+                "required": ["owner", "repo", "sha"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "create_file".to_string(),
+            description: "Create a file in a repository and commit it".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "path": { "type": "string" },
+                    "content": { "type": "string", "description": "File content as plain text" },
+                    "message": { "type": "string", "description": "Commit message" },
+                    "branch": { "type": "string", "description": "Branch to commit to (defaults to the default branch)" },
+                    "author_name": { "type": "string" },
+                    "author_email": { "type": "string" },
+                    "committer_name": { "type": "string", "description": "Defaults to the token owner" },
+                    "committer_email": { "type": "string" }
+                },
+                "required": ["owner", "repo", "path", "content", "message"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "update_file".to_string(),
+            description: "Update a file in a repository and commit the change. Requires the sha of the current version from get_file_content".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "path": { "type": "string" },
+                    "content": { "type": "string", "description": "New file content as plain text" },
+                    "sha": { "type": "string", "description": "Blob sha of the version being replaced" },
+                    "message": { "type": "string", "description": "Commit message" },
+                    "branch": { "type": "string", "description": "Branch to commit to (defaults to the default branch)" },
+                    "author_name": { "type": "string" },
+                    "author_email": { "type": "string" },
+                    "committer_name": { "type": "string", "description": "Defaults to the token owner" },
+                    "committer_email": { "type": "string" }
+                },
+                "required": ["owner", "repo", "path", "content", "sha", "message"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_file".to_string(),
+            description: "Delete a file from a repository and commit the removal".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "path": { "type": "string" },
+                    "sha": { "type": "string", "description": "Blob sha of the file being deleted" },
+                    "message": { "type": "string", "description": "Commit message" },
+                    "branch": { "type": "string", "description": "Branch to commit to (defaults to the default branch)" },
+                    "author_name": { "type": "string" },
+                    "author_email": { "type": "string" },
+                    "committer_name": { "type": "string", "description": "Defaults to the token owner" },
+                    "committer_email": { "type": "string" }
+                },
+                "required": ["owner", "repo", "path", "sha", "message"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
     ]
 }
 
-pub async fn handle_get_file_content(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+/// Split a decode result into `text` and `decode_error` so a failure is explained rather than dropped
+fn split_decoded(decoded: Result<String, GiteeError>) -> (Option<String>, Option<String>) {
+    match decoded {
+        Ok(text) => (Some(text), None),
+        Err(e) => (None, Some(e.to_string())),
+    }
+}
+
+pub async fn handle_get_file_content(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let path = args.get("path").and_then(|v| v.as_str()).ok_or("Missing 'path' parameter")?;
     let r#ref = args.get("ref").and_then(|v| v.as_str());
 
     match client.get_file_content(owner, repo, path, r#ref).await {
-        Ok(content) => Ok(json!({ "content": content })),
-        Err(e) => Err(format!("Failed to get file content: {}", e)),
+        Ok(content) => {
+            // Save callers from decoding base64 themselves; binary files keep only the raw payload
+            let (text, decode_error) = split_decoded(content.decoded_text());
+            Ok(json!({ "content": content, "text": text, "decode_error": decode_error }))
+        }
+        Err(e) => Err(ToolError::gitee("Failed to get file content", &e)),
+    }
+}
+
+pub async fn handle_get_repo_readme(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let r#ref = args.get("ref").and_then(|v| v.as_str());
+
+    match client.get_readme_text(owner, repo, r#ref).await {
+        Ok(readme) => Ok(json!(readme)),
+        Err(e) => Err(ToolError::gitee("Failed to get README", &e)),
+    }
+}
+
+pub async fn handle_render_markdown(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let text = args.get("text").and_then(|v| v.as_str()).ok_or("Missing 'text' parameter")?;
+
+    match client.render_markdown(text).await {
+        Ok(html) => Ok(json!({ "html": html })),
+        Err(e) => Err(ToolError::gitee("Failed to render markdown", &e)),
     }
 }
 
-pub async fn handle_list_repo_files(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_repo_files(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let path = args.get("path").and_then(|v| v.as_str());
@@ -70,11 +224,11 @@ pub async fn handle_list_repo_files(client: &GiteeClient, args: &Value) -> Resul
 
     match client.list_repo_files(owner, repo, path, r#ref).await {
         Ok(files) => Ok(json!({ "files": files })),
-        Err(e) => Err(format!("Failed to list repo files: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list repo files", &e)),
     }
 }
 
-pub async fn handle_search_files_by_content(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_search_files_by_content(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let query = args.get("q").and_then(|v| v.as_str()).ok_or("Missing 'q' parameter")?;
     let owner = args.get("owner").and_then(|v| v.as_str());
     let repo = args.get("repo").and_then(|v| v.as_str());
@@ -83,6 +237,97 @@ pub async fn handle_search_files_by_content(client: &GiteeClient, args: &Value)
 
     match client.search_files_by_content(query, owner, repo, page, per_page).await {
         Ok(files) => Ok(json!({ "files": files })),
-        Err(e) => Err(format!("Failed to search files by content: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to search files by content", &e)),
     }
-}
\ No newline at end of file
+}
+
+pub async fn handle_get_repo_tree(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let sha = args.get("sha").and_then(|v| v.as_str()).ok_or("Missing 'sha' parameter")?;
+    let recursive = args.get("recursive").and_then(|v| v.as_bool()).unwrap_or(true);
+
+    match client.get_tree(owner, repo, sha, recursive).await {
+        Ok(tree) => Ok(json!({ "tree": tree })),
+        Err(e) => Err(ToolError::gitee("Failed to get tree", &e)),
+    }
+}
+
+pub async fn handle_get_repo_blob(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let sha = args.get("sha").and_then(|v| v.as_str()).ok_or("Missing 'sha' parameter")?;
+
+    match client.get_blob(owner, repo, sha).await {
+        Ok(blob) => {
+            let (text, decode_error) = split_decoded(blob.decoded_text());
+            Ok(json!({ "blob": blob, "text": text, "decode_error": decode_error }))
+        }
+        Err(e) => Err(ToolError::gitee("Failed to get blob", &e)),
+    }
+}
+
+fn write_options(args: &Value) -> Result<FileWriteOptions, ToolError> {
+    Ok(FileWriteOptions {
+        branch: args.get("branch").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        author: commit_identity(args, "author")?,
+        committer: commit_identity(args, "committer")?,
+    })
+}
+
+/// Read `<role>_name` and `<role>_email`, which must be given together
+fn commit_identity(args: &Value, role: &str) -> Result<Option<CommitIdentity>, ToolError> {
+    let name = args.get(format!("{}_name", role)).and_then(|v| v.as_str());
+    let email = args.get(format!("{}_email", role)).and_then(|v| v.as_str());
+    match (name, email) {
+        (Some(name), Some(email)) => Ok(Some(CommitIdentity {
+            name: name.to_string(),
+            email: email.to_string(),
+        })),
+        (None, None) => Ok(None),
+        _ => Err(format!("'{}_name' and '{}_email' must be given together", role, role).into()),
+    }
+}
+
+pub async fn handle_create_file(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let path = args.get("path").and_then(|v| v.as_str()).ok_or("Missing 'path' parameter")?;
+    let content = args.get("content").and_then(|v| v.as_str()).ok_or("Missing 'content' parameter")?;
+    let message = args.get("message").and_then(|v| v.as_str()).ok_or("Missing 'message' parameter")?;
+    let options = write_options(args)?;
+
+    match client.create_file(owner, repo, path, content, message, Some(options)).await {
+        Ok(result) => Ok(json!({ "result": result })),
+        Err(e) => Err(ToolError::gitee("Failed to create file", &e)),
+    }
+}
+
+pub async fn handle_update_file(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let path = args.get("path").and_then(|v| v.as_str()).ok_or("Missing 'path' parameter")?;
+    let content = args.get("content").and_then(|v| v.as_str()).ok_or("Missing 'content' parameter")?;
+    let sha = args.get("sha").and_then(|v| v.as_str()).ok_or("Missing 'sha' parameter")?;
+    let message = args.get("message").and_then(|v| v.as_str()).ok_or("Missing 'message' parameter")?;
+    let options = write_options(args)?;
+
+    match client.update_file(owner, repo, path, content, sha, message, Some(options)).await {
+        Ok(result) => Ok(json!({ "result": result })),
+        Err(e) => Err(ToolError::gitee("Failed to update file", &e)),
+    }
+}
+
+pub async fn handle_delete_file(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let path = args.get("path").and_then(|v| v.as_str()).ok_or("Missing 'path' parameter")?;
+    let sha = args.get("sha").and_then(|v| v.as_str()).ok_or("Missing 'sha' parameter")?;
+    let message = args.get("message").and_then(|v| v.as_str()).ok_or("Missing 'message' parameter")?;
+    let options = write_options(args)?;
+
+    match client.delete_file(owner, repo, path, sha, message, Some(options)).await {
+        Ok(result) => Ok(json!({ "result": result })),
+        Err(e) => Err(ToolError::gitee("Failed to delete file", &e)),
+    }
+}
@@ -1,7 +1,7 @@
 use gitee_rs::GiteeClient;
 use gitee_rs::issues::IssueListOptions;
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -29,6 +29,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "get_repo_issue_detail".to_string(),
@@ -42,6 +43,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_issue".to_string(),
@@ -56,6 +58,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "title"]
             }),
+            annotations: None,
         },
         Tool {
             name: "update_issue".to_string(),
@@ -72,6 +75,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "comment_issue".to_string(),
@@ -86,6 +90,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number", "body"]
             }),
+            annotations: None,
         },
         Tool {
             name: "list_issue_comments".to_string(),
@@ -99,6 +104,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "list_repo_milestones".to_string(),
@@ -112,6 +118,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_milestone".to_string(),
@@ -127,6 +134,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "title"]
             }),
+            annotations: None,
         },
         Tool {
             name: "get_milestone".to_string(),
@@ -140,6 +148,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "update_milestone".to_string(),
@@ -156,6 +165,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "delete_milestone".to_string(),
@@ -169,11 +179,12 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
     ]
 }
 
-pub async fn handle_list_issues(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_issues(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
@@ -195,11 +206,11 @@ pub async fn handle_list_issues(client: &GiteeClient, args: &Value) -> Result<Va
 
     match client.list_repo_issues(owner, repo, Some(options)).await {
         Ok(issues) => Ok(json!({ "issues": issues })),
-        Err(e) => Err(format!("Failed to list issues: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list issues", &e)),
     }
 }
 
-pub async fn handle_create_issue(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_issue(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let title = args.get("title").and_then(|v| v.as_str()).ok_or("Missing 'title' parameter")?;
@@ -207,33 +218,33 @@ pub async fn handle_create_issue(client: &GiteeClient, args: &Value) -> Result<V
 
     match client.create_issue(owner, repo, title, body).await {
         Ok(issue) => Ok(json!({ "issue": issue })),
-        Err(e) => Err(format!("Failed to create issue: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create issue", &e)),
     }
 }
 
-pub async fn handle_close_issue(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_close_issue(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
     match client.close_issue(owner, repo, number).await {
         Ok(issue) => Ok(json!({ "issue": issue })),
-        Err(e) => Err(format!("Failed to close issue: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to close issue", &e)),
     }
 }
 
-pub async fn handle_get_issue_detail(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_issue_detail(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
     match client.get_issue_detail(owner, repo, number).await {
         Ok(issue) => Ok(json!({ "issue": issue })),
-        Err(e) => Err(format!("Failed to get issue detail: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get issue detail", &e)),
     }
 }
 
-pub async fn handle_update_issue(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_update_issue(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
@@ -243,11 +254,11 @@ pub async fn handle_update_issue(client: &GiteeClient, args: &Value) -> Result<V
 
     match client.update_issue(owner, repo, number, title, body, state).await {
         Ok(issue) => Ok(json!({ "issue": issue })),
-        Err(e) => Err(format!("Failed to update issue: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to update issue", &e)),
     }
 }
 
-pub async fn handle_comment_issue(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_comment_issue(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
@@ -255,11 +266,11 @@ pub async fn handle_comment_issue(client: &GiteeClient, args: &Value) -> Result<
 
     match client.comment_issue(owner, repo, number, body).await {
         Ok(comment) => Ok(json!({ "comment": comment })),
-        Err(e) => Err(format!("Failed to comment on issue: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to comment on issue", &e)),
     }
 }
 
-pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
@@ -268,7 +279,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(comments) => Ok(json!({ "comments": comments })),
 
-            Err(e) => Err(format!("Failed to list issue comments: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to list issue comments", &e)),
 
         }
 
@@ -276,7 +287,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
     
 
-    pub async fn handle_list_repo_milestones(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_list_repo_milestones(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -290,7 +301,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(milestones) => Ok(json!({ "milestones": milestones })),
 
-            Err(e) => Err(format!("Failed to list milestones: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to list milestones", &e)),
 
         }
 
@@ -298,7 +309,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
     
 
-    pub async fn handle_create_milestone(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_create_milestone(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -316,7 +327,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(milestone) => Ok(json!({ "milestone": milestone })),
 
-            Err(e) => Err(format!("Failed to create milestone: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to create milestone", &e)),
 
         }
 
@@ -324,7 +335,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
     
 
-    pub async fn handle_get_milestone(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_get_milestone(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -338,7 +349,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(milestone) => Ok(json!({ "milestone": milestone })),
 
-            Err(e) => Err(format!("Failed to get milestone: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to get milestone", &e)),
 
         }
 
@@ -346,7 +357,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
     
 
-    pub async fn handle_update_milestone(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_update_milestone(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -366,7 +377,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(milestone) => Ok(json!({ "milestone": milestone })),
 
-            Err(e) => Err(format!("Failed to update milestone: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to update milestone", &e)),
 
         }
 
@@ -374,7 +385,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
     
 
-    pub async fn handle_delete_milestone(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_delete_milestone(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -388,7 +399,7 @@ pub async fn handle_list_issue_comments(client: &GiteeClient, args: &Value) -> R
 
             Ok(_) => Ok(json!({ "status": "success" })),
 
-            Err(e) => Err(format!("Failed to delete milestone: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to delete milestone", &e)),
 
         }
 
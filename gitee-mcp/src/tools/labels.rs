@@ -1,6 +1,6 @@
 use gitee_rs::GiteeClient;
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -15,6 +15,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_label".to_string(),
@@ -30,6 +31,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "name", "color"]
             }),
+            annotations: None,
         },
         Tool {
             name: "update_label".to_string(),
@@ -46,6 +48,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "name"]
             }),
+            annotations: None,
         },
         Tool {
             name: "delete_label".to_string(),
@@ -59,21 +62,22 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "name"]
             }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
     ]
 }
 
-pub async fn handle_list_labels(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_labels(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
     match client.list_labels(owner, repo).await {
         Ok(labels) => Ok(json!({ "labels": labels })),
-        Err(e) => Err(format!("Failed to list labels: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list labels", &e)),
     }
 }
 
-pub async fn handle_create_label(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_label(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
@@ -82,11 +86,11 @@ pub async fn handle_create_label(client: &GiteeClient, args: &Value) -> Result<V
 
     match client.create_label(owner, repo, name, color, description).await {
         Ok(label) => Ok(json!({ "label": label })),
-        Err(e) => Err(format!("Failed to create label: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create label", &e)),
     }
 }
 
-pub async fn handle_update_label(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_update_label(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
@@ -96,17 +100,17 @@ pub async fn handle_update_label(client: &GiteeClient, args: &Value) -> Result<V
 
     match client.update_label(owner, repo, name, new_name, color, description).await {
         Ok(label) => Ok(json!({ "label": label })),
-        Err(e) => Err(format!("Failed to update label: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to update label", &e)),
     }
 }
 
-pub async fn handle_delete_label(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_delete_label(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
 
     match client.delete_label(owner, repo, name).await {
         Ok(()) => Ok(json!({ "success": true, "message": format!("Successfully deleted label: {}", name) })),
-        Err(e) => Err(format!("Failed to delete label: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to delete label", &e)),
     }
 }
\ No newline at end of file
@@ -6,6 +6,11 @@ pub mod users;
 pub mod notifications;
 pub mod files;
 pub mod wikis;
+pub mod branches;
+pub mod commits;
+pub mod tags;
+pub mod collaborators;
+pub mod enterprises;
 pub mod definitions;
 pub mod dispatcher;
 
@@ -84,10 +89,14 @@ pub async fn handle_rpc_request(
             match dispatch_tool_call(client, tool_name, arguments).await {
                 Ok(result) => result,
                 Err(e) => {
+                    let mut error = json!({ "code": -1, "message": e.message });
+                    if let Some(data) = e.data {
+                        error["data"] = data;
+                    }
                     return json!({
                         "jsonrpc": "2.0",
                         "id": id,
-                        "error": { "code": -1, "message": e }
+                        "error": error
                     });
                 }
             }
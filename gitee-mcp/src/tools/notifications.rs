@@ -1,6 +1,6 @@
 use gitee_rs::GiteeClient;
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -8,13 +8,14 @@ pub fn get_tool_definitions() -> Vec<Tool> {
             name: "list_user_notifications".to_string(),
             description: "List notifications for the authenticated user".to_string(),
             input_schema: json!({ "type": "object", "properties": {} }),
+            annotations: None,
         },
     ]
 }
 
-pub async fn handle_list_notifications(client: &GiteeClient) -> Result<Value, String> {
+pub async fn handle_list_notifications(client: &GiteeClient) -> Result<Value, ToolError> {
     match client.list_user_notifications().await {
         Ok(notifications) => Ok(json!({ "notifications": notifications })),
-        Err(e) => Err(format!("Failed to list notifications: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list notifications", &e)),
     }
 }
@@ -1,7 +1,7 @@
-use gitee_rs::GiteeClient;
-use gitee_rs::pulls::PullListOptions;
+use gitee_rs::{GiteeClient, PullReviewStatus};
+use gitee_rs::pulls::{DiffPosition, MergeMethod, MergeOptions, PullListOptions};
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -23,6 +23,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "get_pull_detail".to_string(),
@@ -36,6 +37,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_pull".to_string(),
@@ -52,6 +54,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "title", "head", "base"]
             }),
+            annotations: None,
         },
         Tool {
             name: "update_pull".to_string(),
@@ -68,19 +71,26 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "merge_pull".to_string(),
-            description: "Merge a pull request".to_string(),
+            description: "Merge a pull request. Refuses when the pull request has conflicts or is missing required reviews or tests unless force is true".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "owner": { "type": "string" },
                     "repo": { "type": "string" },
-                    "number": { "type": "string" }
+                    "number": { "type": "string" },
+                    "merge_method": { "type": "string", "enum": ["merge", "squash", "rebase"] },
+                    "title": { "type": "string", "description": "Merge commit title" },
+                    "description": { "type": "string", "description": "Merge commit description" },
+                    "prune_source_branch": { "type": "boolean", "description": "Delete the source branch after merging" },
+                    "force": { "type": "boolean", "description": "Skip the mergeability and review checks" }
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
         },
         Tool {
             name: "comment_pull".to_string(),
@@ -95,6 +105,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number", "body"]
             }),
+            annotations: None,
         },
         Tool {
             name: "list_pull_comments".to_string(),
@@ -108,6 +119,114 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
+        },
+        Tool {
+            name: "create_pull_review_comment".to_string(),
+            description: "Comment on a specific line of a pull request diff".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "body": { "type": "string" },
+                    "path": { "type": "string", "description": "File path in the diff" },
+                    "position": { "type": "integer", "description": "Line index within the file's diff, counting from the first @@ hunk header" },
+                    "commit_id": { "type": "string", "description": "Commit to comment on (defaults to the pull request head)" }
+                },
+                "required": ["owner", "repo", "number", "body", "path", "position"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "list_pull_review_comments".to_string(),
+            description: "List line-level review comments of a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "reply_pull_review_comment".to_string(),
+            description: "Reply to a review comment on the same diff line".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "comment_id": { "type": "string" },
+                    "body": { "type": "string" }
+                },
+                "required": ["owner", "repo", "number", "comment_id", "body"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "update_pull_comment".to_string(),
+            description: "Edit a pull request comment".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "comment_id": { "type": "string" },
+                    "body": { "type": "string" }
+                },
+                "required": ["owner", "repo", "comment_id", "body"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_pull_comment".to_string(),
+            description: "Delete a pull request comment".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "comment_id": { "type": "string" }
+                },
+                "required": ["owner", "repo", "comment_id"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+        Tool {
+            name: "list_pull_commits".to_string(),
+            description: "List the commits of a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_pull_diff".to_string(),
+            description: "Get the unified diff (or format-patch text) of a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "format": { "type": "string", "enum": ["diff", "patch"], "description": "Defaults to diff" },
+                    "max_length": { "type": "integer", "description": "Truncate the text to this many characters" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
         },
         Tool {
             name: "get_diff_files".to_string(),
@@ -121,11 +240,146 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "number"]
             }),
+            annotations: None,
+        },
+        Tool {
+            name: "add_pull_reviewers".to_string(),
+            description: "Assign reviewers to a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "users": { "type": "array", "items": { "type": "string" }, "description": "Usernames" }
+                },
+                "required": ["owner", "repo", "number", "users"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "remove_pull_reviewers".to_string(),
+            description: "Remove reviewers from a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "users": { "type": "array", "items": { "type": "string" }, "description": "Usernames" }
+                },
+                "required": ["owner", "repo", "number", "users"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "add_pull_testers".to_string(),
+            description: "Assign testers to a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "users": { "type": "array", "items": { "type": "string" }, "description": "Usernames" }
+                },
+                "required": ["owner", "repo", "number", "users"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "remove_pull_testers".to_string(),
+            description: "Remove testers from a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "users": { "type": "array", "items": { "type": "string" }, "description": "Usernames" }
+                },
+                "required": ["owner", "repo", "number", "users"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "approve_pull_review".to_string(),
+            description: "Approve the review of a pull request (审查通过)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "force": { "type": "boolean", "description": "Pass on behalf of everyone (repository admins only)" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "pass_pull_test".to_string(),
+            description: "Mark the test of a pull request as passed (测试通过)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "force": { "type": "boolean", "description": "Pass on behalf of everyone (repository admins only)" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "reset_pull_review".to_string(),
+            description: "Reset the review state of a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "reset_all": { "type": "boolean", "description": "Also reset everyone else's state" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "reset_pull_test".to_string(),
+            description: "Reset the test state of a pull request".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" },
+                    "reset_all": { "type": "boolean", "description": "Also reset everyone else's state" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_pull_review_status".to_string(),
+            description: "Get reviewers, testers and whether the review and test requirements of a pull request are met".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "number": { "type": "string" }
+                },
+                "required": ["owner", "repo", "number"]
+            }),
+            annotations: None,
         },
     ]
 }
 
-pub async fn handle_list_pulls(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_pulls(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
@@ -143,22 +397,22 @@ pub async fn handle_list_pulls(client: &GiteeClient, args: &Value) -> Result<Val
 
     match client.list_pulls(owner, repo, Some(options)).await {
         Ok(pulls) => Ok(json!({ "pull_requests": pulls })),
-        Err(e) => Err(format!("Failed to list pull requests: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list pull requests", &e)),
     }
 }
 
-pub async fn handle_get_pull_detail(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_pull_detail(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
     match client.get_pull_detail(owner, repo, number).await {
         Ok(pull) => Ok(json!({ "pull_request": pull })),
-        Err(e) => Err(format!("Failed to get pull request detail: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get pull request detail", &e)),
     }
 }
 
-pub async fn handle_create_pull(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_pull(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let title = args.get("title").and_then(|v| v.as_str()).ok_or("Missing 'title' parameter")?;
@@ -168,11 +422,11 @@ pub async fn handle_create_pull(client: &GiteeClient, args: &Value) -> Result<Va
 
     match client.create_pull(owner, repo, title, head, base, body).await {
         Ok(pull) => Ok(json!({ "pull_request": pull })),
-        Err(e) => Err(format!("Failed to create pull request: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create pull request", &e)),
     }
 }
 
-pub async fn handle_update_pull(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_update_pull(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
@@ -182,22 +436,46 @@ pub async fn handle_update_pull(client: &GiteeClient, args: &Value) -> Result<Va
 
     match client.update_pull(owner, repo, number, title, body, state).await {
         Ok(pull) => Ok(json!({ "pull_request": pull })),
-        Err(e) => Err(format!("Failed to update pull request: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to update pull request", &e)),
     }
 }
 
-pub async fn handle_merge_pull(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_merge_pull(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
-    match client.merge_pull(owner, repo, number).await {
+    let merge_method = match args.get("merge_method").and_then(|v| v.as_str()) {
+        Some(name) => Some(MergeMethod::parse(name).ok_or(format!("Unsupported merge_method '{}'", name))?),
+        None => None,
+    };
+    let force = args.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    if !force {
+        let pull = client
+            .get_pull_detail(owner, repo, number)
+            .await
+            .map_err(|e| ToolError::gitee("Failed to check pull request before merging", &e))?;
+        let blockers = pull.merge_blockers();
+        if !blockers.is_empty() {
+            return Err(format!("Pull request is not ready to merge: {}", blockers.join("; ")).into());
+        }
+    }
+
+    let options = MergeOptions {
+        merge_method,
+        title: args.get("title").and_then(|v| v.as_str()).map(String::from),
+        description: args.get("description").and_then(|v| v.as_str()).map(String::from),
+        prune_source_branch: args.get("prune_source_branch").and_then(|v| v.as_bool()),
+    };
+
+    match client.merge_pull(owner, repo, number, Some(options)).await {
         Ok(pull) => Ok(json!({ "pull_request": pull })),
-        Err(e) => Err(format!("Failed to merge pull request: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to merge pull request", &e)),
     }
 }
 
-pub async fn handle_comment_pull(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_comment_pull(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
@@ -205,28 +483,252 @@ pub async fn handle_comment_pull(client: &GiteeClient, args: &Value) -> Result<V
 
     match client.comment_pull(owner, repo, number, body).await {
         Ok(comment) => Ok(json!({ "comment": comment })),
-        Err(e) => Err(format!("Failed to comment on pull request: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to comment on pull request", &e)),
     }
 }
 
-pub async fn handle_list_pull_comments(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_pull_comments(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
     match client.list_pull_comments(owner, repo, number).await {
         Ok(comments) => Ok(json!({ "comments": comments })),
-        Err(e) => Err(format!("Failed to list pull request comments: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list pull request comments", &e)),
     }
 }
 
-pub async fn handle_get_diff_files(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_diff_files(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
 
     match client.get_diff_files(owner, repo, number).await {
         Ok(files) => Ok(json!({ "files": files })),
-        Err(e) => Err(format!("Failed to get diff files: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get diff files", &e)),
+    }
+}
+
+// Accept either an array of usernames or a comma-separated string
+fn user_list(args: &Value) -> Result<Vec<&str>, ToolError> {
+    match args.get("users") {
+        Some(Value::Array(users)) => Ok(users.iter().filter_map(|v| v.as_str()).collect()),
+        Some(Value::String(users)) => Ok(users.split(',').map(|u| u.trim()).filter(|u| !u.is_empty()).collect()),
+        _ => Err("Missing 'users' parameter".into()),
+    }
+}
+
+pub async fn handle_add_pull_reviewers(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let users = user_list(args)?;
+
+    match client.add_pull_reviewers(owner, repo, number, &users).await {
+        Ok(pull) => Ok(json!({ "review_status": PullReviewStatus::from_pull(&pull) })),
+        Err(e) => Err(ToolError::gitee("Failed to assign reviewers", &e)),
+    }
+}
+
+pub async fn handle_remove_pull_reviewers(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let users = user_list(args)?;
+
+    match client.remove_pull_reviewers(owner, repo, number, &users).await {
+        Ok(pull) => Ok(json!({ "review_status": PullReviewStatus::from_pull(&pull) })),
+        Err(e) => Err(ToolError::gitee("Failed to remove reviewers", &e)),
+    }
+}
+
+pub async fn handle_add_pull_testers(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let users = user_list(args)?;
+
+    match client.add_pull_testers(owner, repo, number, &users).await {
+        Ok(pull) => Ok(json!({ "review_status": PullReviewStatus::from_pull(&pull) })),
+        Err(e) => Err(ToolError::gitee("Failed to assign testers", &e)),
+    }
+}
+
+pub async fn handle_remove_pull_testers(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let users = user_list(args)?;
+
+    match client.remove_pull_testers(owner, repo, number, &users).await {
+        Ok(pull) => Ok(json!({ "review_status": PullReviewStatus::from_pull(&pull) })),
+        Err(e) => Err(ToolError::gitee("Failed to remove testers", &e)),
+    }
+}
+
+pub async fn handle_approve_pull_review(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let force = args.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    match client.approve_pull_review(owner, repo, number, force).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to approve review", &e)),
+    }
+}
+
+pub async fn handle_pass_pull_test(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let force = args.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    match client.pass_pull_test(owner, repo, number, force).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to pass test", &e)),
+    }
+}
+
+pub async fn handle_reset_pull_review(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let reset_all = args.get("reset_all").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    match client.reset_pull_review(owner, repo, number, reset_all).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to reset review", &e)),
+    }
+}
+
+pub async fn handle_reset_pull_test(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let reset_all = args.get("reset_all").and_then(|v| v.as_bool()).unwrap_or(false);
+
+    match client.reset_pull_test(owner, repo, number, reset_all).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to reset test", &e)),
+    }
+}
+
+pub async fn handle_get_pull_review_status(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+
+    match client.get_pull_review_status(owner, repo, number).await {
+        Ok(status) => Ok(json!({ "review_status": status })),
+        Err(e) => Err(ToolError::gitee("Failed to get review status", &e)),
+    }
+}
+
+pub async fn handle_create_pull_review_comment(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let body = args.get("body").and_then(|v| v.as_str()).ok_or("Missing 'body' parameter")?;
+    let path = args.get("path").and_then(|v| v.as_str()).ok_or("Missing 'path' parameter")?;
+    let position = args.get("position").and_then(|v| v.as_i64()).ok_or("Missing 'position' parameter")? as i32;
+    let commit_id = match args.get("commit_id").and_then(|v| v.as_str()) {
+        Some(commit_id) => commit_id.to_string(),
+        None => client
+            .get_pull_detail(owner, repo, number)
+            .await
+            .map_err(|e| ToolError::gitee("Failed to get pull request head", &e))?
+            .head
+            .sha,
+    };
+    let anchor = DiffPosition { commit_id, path: path.to_string(), position };
+
+    match client.create_pull_review_comment(owner, repo, number, body, &anchor).await {
+        Ok(comment) => Ok(json!({ "comment": comment })),
+        Err(e) => Err(ToolError::gitee("Failed to create review comment", &e)),
     }
-}
\ No newline at end of file
+}
+
+pub async fn handle_list_pull_review_comments(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+
+    match client.list_pull_review_comments(owner, repo, number).await {
+        Ok(comments) => Ok(json!({ "comments": comments })),
+        Err(e) => Err(ToolError::gitee("Failed to list review comments", &e)),
+    }
+}
+
+pub async fn handle_reply_pull_review_comment(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let comment_id = args.get("comment_id").and_then(|v| v.as_str()).ok_or("Missing 'comment_id' parameter")?;
+    let body = args.get("body").and_then(|v| v.as_str()).ok_or("Missing 'body' parameter")?;
+
+    match client.reply_pull_review_comment(owner, repo, number, comment_id, body).await {
+        Ok(comment) => Ok(json!({ "comment": comment })),
+        Err(e) => Err(ToolError::gitee("Failed to reply to review comment", &e)),
+    }
+}
+
+pub async fn handle_update_pull_comment(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let comment_id = args.get("comment_id").and_then(|v| v.as_str()).ok_or("Missing 'comment_id' parameter")?;
+    let body = args.get("body").and_then(|v| v.as_str()).ok_or("Missing 'body' parameter")?;
+
+    match client.update_pull_comment(owner, repo, comment_id, body).await {
+        Ok(comment) => Ok(json!({ "comment": comment })),
+        Err(e) => Err(ToolError::gitee("Failed to update comment", &e)),
+    }
+}
+
+pub async fn handle_delete_pull_comment(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let comment_id = args.get("comment_id").and_then(|v| v.as_str()).ok_or("Missing 'comment_id' parameter")?;
+
+    match client.delete_pull_comment(owner, repo, comment_id).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete comment", &e)),
+    }
+}
+
+pub async fn handle_list_pull_commits(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+
+    match client.list_pull_commits(owner, repo, number).await {
+        Ok(commits) => Ok(json!({ "commits": commits })),
+        Err(e) => Err(ToolError::gitee("Failed to list pull request commits", &e)),
+    }
+}
+
+pub async fn handle_get_pull_diff(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let number = args.get("number").and_then(|v| v.as_str()).ok_or("Missing 'number' parameter")?;
+    let format = args.get("format").and_then(|v| v.as_str()).unwrap_or("diff");
+    let max_length = args.get("max_length").and_then(|v| v.as_u64()).map(|v| v as usize);
+
+    let result = match format {
+        "patch" => client.get_pull_patch(owner, repo, number).await,
+        "diff" => client.get_pull_diff(owner, repo, number).await,
+        other => return Err(format!("Unsupported format '{}', expected 'diff' or 'patch'", other).into()),
+    };
+
+    match result {
+        Ok(text) => {
+            let truncated = max_length.is_some_and(|max| text.chars().count() > max);
+            let text: String = match max_length {
+                Some(max) => text.chars().take(max).collect(),
+                None => text,
+            };
+            Ok(json!({ "diff": text, "truncated": truncated }))
+        }
+        Err(e) => Err(ToolError::gitee("Failed to get pull request diff", &e)),
+    }
+}
@@ -1,6 +1,6 @@
-use gitee_rs::GiteeClient;
+use gitee_rs::{releases::{ReleaseOptions, ReleaseUpdate}, GiteeClient};
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -8,6 +8,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
             name: "list_user_repos".to_string(),
             description: "List all repositories accessible to the authenticated user".to_string(),
             input_schema: json!({ "type": "object", "properties": {} }),
+            annotations: None,
         },
         Tool {
             name: "get_repo".to_string(),
@@ -20,6 +21,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_user_repo".to_string(),
@@ -33,6 +35,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["name"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_org_repo".to_string(),
@@ -47,6 +50,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["org", "name"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_enterprise_repo".to_string(),
@@ -61,6 +65,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["enterprise", "name"]
             }),
+            annotations: None,
         },
         Tool {
             name: "delete_repo".to_string(),
@@ -73,6 +78,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
         Tool {
             name: "fork_repository".to_string(),
@@ -85,6 +91,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "search_open_source_repositories".to_string(),
@@ -96,6 +103,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["q"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_release".to_string(),
@@ -107,10 +115,14 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                     "repo": { "type": "string" },
                     "tag_name": { "type": "string" },
                     "name": { "type": "string" },
-                    "body": { "type": "string" }
+                    "body": { "type": "string" },
+                    "target_commitish": { "type": "string", "description": "Branch or commit to tag (defaults to the default branch)" },
+                    "prerelease": { "type": "boolean" },
+                    "draft": { "type": "boolean" }
                 },
                 "required": ["owner", "repo", "tag_name", "name"]
             }),
+            annotations: None,
         },
         Tool {
             name: "list_releases".to_string(),
@@ -123,6 +135,140 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_release".to_string(),
+            description: "Get a release by id".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_latest_release".to_string(),
+            description: "Get the latest release of a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "get_release_by_tag".to_string(),
+            description: "Get the release for a tag".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "tag": { "type": "string" }
+                },
+                "required": ["owner", "repo", "tag"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "update_release".to_string(),
+            description: "Update a release; omitted fields keep their current value".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" },
+                    "tag_name": { "type": "string" },
+                    "name": { "type": "string" },
+                    "body": { "type": "string" },
+                    "prerelease": { "type": "boolean" }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_release".to_string(),
+            description: "Delete a release (the tag is kept)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+        Tool {
+            name: "list_release_assets".to_string(),
+            description: "List files attached to a release".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" }
+                },
+                "required": ["owner", "repo", "release_id"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "upload_release_asset".to_string(),
+            description: "Upload a local file as a release attachment".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" },
+                    "file_path": { "type": "string", "description": "Path of the file on the machine running the server" }
+                },
+                "required": ["owner", "repo", "release_id", "file_path"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "download_release_asset".to_string(),
+            description: "Download a release attachment to a local file".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" },
+                    "asset_id": { "type": "string" },
+                    "output_path": { "type": "string", "description": "Where to write the file on the machine running the server" }
+                },
+                "required": ["owner", "repo", "release_id", "asset_id", "output_path"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_release_asset".to_string(),
+            description: "Delete a release attachment".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "release_id": { "type": "string" },
+                    "asset_id": { "type": "string" }
+                },
+                "required": ["owner", "repo", "release_id", "asset_id"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
         Tool {
             name: "star_repo".to_string(),
@@ -135,6 +281,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "unstar_repo".to_string(),
@@ -147,6 +294,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "watch_repo".to_string(),
@@ -159,6 +307,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "unwatch_repo".to_string(),
@@ -171,32 +320,33 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
     ]
 }
 
-pub async fn handle_get_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
     match client.get_repo(owner, repo).await {
         Ok(repo) => Ok(json!({ "repository": repo })),
-        Err(e) => Err(format!("Failed to get repository: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get repository", &e)),
     }
 }
 
-pub async fn handle_create_user_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_user_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
     let description = args.get("description").and_then(|v| v.as_str());
     let private = args.get("private").and_then(|v| v.as_bool()).unwrap_or(true);
 
     match client.create_user_repo(name, description, private).await {
         Ok(repo) => Ok(json!({ "repository": repo })),
-        Err(e) => Err(format!("Failed to create repository: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create repository", &e)),
     }
 }
 
-pub async fn handle_create_org_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_org_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let org = args.get("org").and_then(|v| v.as_str()).ok_or("Missing 'org' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
     let description = args.get("description").and_then(|v| v.as_str());
@@ -204,11 +354,11 @@ pub async fn handle_create_org_repo(client: &GiteeClient, args: &Value) -> Resul
 
     match client.create_org_repo(org, name, description, private).await {
         Ok(repo) => Ok(json!({ "repository": repo })),
-        Err(e) => Err(format!("Failed to create org repository: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create org repository", &e)),
     }
 }
 
-pub async fn handle_create_enterprise_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_enterprise_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let enterprise = args.get("enterprise").and_then(|v| v.as_str()).ok_or("Missing 'enterprise' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
     let description = args.get("description").and_then(|v| v.as_str());
@@ -216,28 +366,28 @@ pub async fn handle_create_enterprise_repo(client: &GiteeClient, args: &Value) -
 
     match client.create_enterprise_repo(enterprise, name, description, private).await {
         Ok(repo) => Ok(json!({ "repository": repo })),
-        Err(e) => Err(format!("Failed to create enterprise repository: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create enterprise repository", &e)),
     }
 }
 
-pub async fn handle_list_user_repos(client: &GiteeClient) -> Result<Value, String> {
+pub async fn handle_list_user_repos(client: &GiteeClient) -> Result<Value, ToolError> {
     match client.list_user_repos().await {
         Ok(repos) => Ok(json!({ "repositories": repos })),
-        Err(e) => Err(format!("Failed to list repositories: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list repositories", &e)),
     }
 }
 
-pub async fn handle_fork_repository(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_fork_repository(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
     match client.fork_repository(owner, repo).await {
         Ok(repo) => Ok(json!({ "repository": repo })),
-        Err(e) => Err(format!("Failed to fork repository: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to fork repository", &e)),
     }
 }
 
-pub async fn handle_search_repositories(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_search_repositories(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let query = args.get("q").and_then(|v| v.as_str()).ok_or("Missing 'q' parameter")?;
     let from = args.get("from").and_then(|v| v.as_i64()).map(|v| v as i32);
     let size = args.get("size").and_then(|v| v.as_i64()).map(|v| v as i32);
@@ -245,24 +395,144 @@ pub async fn handle_search_repositories(client: &GiteeClient, args: &Value) -> R
 
     match client.search_repositories(query, from, size, sort).await {
         Ok(repos) => Ok(json!({ "repositories": repos })),
-        Err(e) => Err(format!("Failed to search repositories: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to search repositories", &e)),
     }
 }
 
-pub async fn handle_create_release(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_release(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let tag_name = args.get("tag_name").and_then(|v| v.as_str()).ok_or("Missing 'tag_name' parameter")?;
     let name = args.get("name").and_then(|v| v.as_str()).ok_or("Missing 'name' parameter")?;
     let body = args.get("body").and_then(|v| v.as_str());
+    let options = ReleaseOptions {
+        target_commitish: args.get("target_commitish").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        prerelease: args.get("prerelease").and_then(|v| v.as_bool()),
+        draft: args.get("draft").and_then(|v| v.as_bool()),
+    };
+
+    match client.create_release(owner, repo, tag_name, name, body, Some(options)).await {
+        Ok(release) => Ok(json!({ "release": release })),
+        Err(e) => Err(ToolError::gitee("Failed to create release", &e)),
+    }
+}
+
+pub async fn handle_get_release(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+
+    match client.get_release(owner, repo, release_id).await {
+        Ok(release) => Ok(json!({ "release": release })),
+        Err(e) => Err(ToolError::gitee("Failed to get release", &e)),
+    }
+}
+
+pub async fn handle_get_latest_release(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+
+    match client.get_latest_release(owner, repo).await {
+        Ok(release) => Ok(json!({ "release": release })),
+        Err(e) => Err(ToolError::gitee("Failed to get latest release", &e)),
+    }
+}
+
+pub async fn handle_get_release_by_tag(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let tag = args.get("tag").and_then(|v| v.as_str()).ok_or("Missing 'tag' parameter")?;
+
+    match client.get_release_by_tag(owner, repo, tag).await {
+        Ok(release) => Ok(json!({ "release": release })),
+        Err(e) => Err(ToolError::gitee("Failed to get release by tag", &e)),
+    }
+}
 
-    match client.create_release(owner, repo, tag_name, name, body).await {
+pub async fn handle_update_release(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+    let update = ReleaseUpdate {
+        tag_name: args.get("tag_name").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        name: args.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        body: args.get("body").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        prerelease: args.get("prerelease").and_then(|v| v.as_bool()),
+    };
+
+    match client.update_release(owner, repo, release_id, update).await {
         Ok(release) => Ok(json!({ "release": release })),
-        Err(e) => Err(format!("Failed to create release: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to update release", &e)),
+    }
+}
+
+pub async fn handle_delete_release(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+
+    match client.delete_release(owner, repo, release_id).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete release", &e)),
+    }
+}
+
+pub async fn handle_list_release_assets(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+
+    match client.list_release_assets(owner, repo, release_id).await {
+        Ok(assets) => Ok(json!({ "assets": assets })),
+        Err(e) => Err(ToolError::gitee("Failed to list release assets", &e)),
+    }
+}
+
+pub async fn handle_upload_release_asset(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+    let file_path = args.get("file_path").and_then(|v| v.as_str()).ok_or("Missing 'file_path' parameter")?;
+
+    match client.upload_release_asset(owner, repo, release_id, file_path).await {
+        Ok(asset) => Ok(json!({ "asset": asset })),
+        Err(e) => Err(ToolError::gitee("Failed to upload release asset", &e)),
+    }
+}
+
+pub async fn handle_download_release_asset(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+    let asset_id = args.get("asset_id").and_then(|v| v.as_str()).ok_or("Missing 'asset_id' parameter")?;
+    let output_path = args.get("output_path").and_then(|v| v.as_str()).ok_or("Missing 'output_path' parameter")?;
+
+    let mut file = tokio::fs::File::create(output_path)
+        .await
+        .map_err(|e| format!("Failed to create '{}': {}", output_path, e))?;
+    match client.download_release_asset(owner, repo, release_id, asset_id, &mut file).await {
+        Ok(bytes) => Ok(json!({ "path": output_path, "bytes": bytes })),
+        Err(e) => {
+            drop(file);
+            let _ = tokio::fs::remove_file(output_path).await;
+            Err(ToolError::gitee("Failed to download release asset", &e))
+        }
+    }
+}
+
+pub async fn handle_delete_release_asset(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let release_id = args.get("release_id").and_then(|v| v.as_str()).ok_or("Missing 'release_id' parameter")?;
+    let asset_id = args.get("asset_id").and_then(|v| v.as_str()).ok_or("Missing 'asset_id' parameter")?;
+
+    match client.delete_release_asset(owner, repo, release_id, asset_id).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete release asset", &e)),
     }
 }
 
-pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
@@ -270,7 +540,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
                 Ok(releases) => Ok(json!({ "releases": releases })),
 
-                Err(e) => Err(format!("Failed to list releases: {}", e)),
+                Err(e) => Err(ToolError::gitee("Failed to list releases", &e)),
 
             }
 
@@ -278,7 +548,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
         
 
-        pub async fn handle_delete_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+        pub async fn handle_delete_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
             let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -290,7 +560,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
                 Ok(_) => Ok(json!({ "status": "success" })),
 
-                Err(e) => Err(format!("Failed to delete repository: {}", e)),
+                Err(e) => Err(ToolError::gitee("Failed to delete repository", &e)),
 
             }
 
@@ -298,7 +568,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
         
 
-        pub async fn handle_star_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+        pub async fn handle_star_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         
 
@@ -312,7 +582,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
             Ok(_) => Ok(json!({ "status": "success" })),
 
-            Err(e) => Err(format!("Failed to star repository: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to star repository", &e)),
 
         }
 
@@ -320,7 +590,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
     
 
-    pub async fn handle_unstar_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_unstar_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -332,7 +602,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
             Ok(_) => Ok(json!({ "status": "success" })),
 
-            Err(e) => Err(format!("Failed to unstar repository: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to unstar repository", &e)),
 
         }
 
@@ -340,7 +610,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
     
 
-    pub async fn handle_watch_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_watch_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -352,7 +622,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
             Ok(_) => Ok(json!({ "status": "success" })),
 
-            Err(e) => Err(format!("Failed to watch repository: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to watch repository", &e)),
 
         }
 
@@ -360,7 +630,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
     
 
-    pub async fn handle_unwatch_repo(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+    pub async fn handle_unwatch_repo(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
 
         let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
 
@@ -372,7 +642,7 @@ pub async fn handle_list_releases(client: &GiteeClient, args: &Value) -> Result<
 
             Ok(_) => Ok(json!({ "status": "success" })),
 
-            Err(e) => Err(format!("Failed to unwatch repository: {}", e)),
+            Err(e) => Err(ToolError::gitee("Failed to unwatch repository", &e)),
 
         }
 
@@ -0,0 +1,85 @@
+use gitee_rs::GiteeClient;
+use serde_json::{json, Value};
+use crate::{Tool, ToolError};
+
+pub fn get_tool_definitions() -> Vec<Tool> {
+    vec![
+        Tool {
+            name: "list_repo_tags".to_string(),
+            description: "List tags of a repository".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" }
+                },
+                "required": ["owner", "repo"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "create_tag".to_string(),
+            description: "Create a tag on a branch or commit. Setting a message creates an annotated tag".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "tag_name": { "type": "string" },
+                    "refs": { "type": "string", "description": "Branch or commit to tag" },
+                    "message": { "type": "string" }
+                },
+                "required": ["owner", "repo", "tag_name", "refs"]
+            }),
+            annotations: None,
+        },
+        Tool {
+            name: "delete_tag".to_string(),
+            description: "Delete a tag".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "owner": { "type": "string" },
+                    "repo": { "type": "string" },
+                    "tag_name": { "type": "string" }
+                },
+                "required": ["owner", "repo", "tag_name"]
+            }),
+            annotations: Some(json!({ "destructiveHint": true })),
+        },
+    ]
+}
+
+pub async fn handle_list_tags(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+
+    match client.list_tags(owner, repo).await {
+        Ok(tags) => Ok(json!({ "tags": tags })),
+        Err(e) => Err(ToolError::gitee("Failed to list tags", &e)),
+    }
+}
+
+pub async fn handle_create_tag(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let tag_name = args.get("tag_name").and_then(|v| v.as_str()).ok_or("Missing 'tag_name' parameter")?;
+    let refs = args.get("refs").and_then(|v| v.as_str()).ok_or("Missing 'refs' parameter")?;
+    let message = args.get("message").and_then(|v| v.as_str());
+
+    match client.create_tag(owner, repo, tag_name, refs, message).await {
+        Ok(tag) => Ok(json!({ "tag": tag })),
+        Err(e) => Err(ToolError::gitee("Failed to create tag", &e)),
+    }
+}
+
+pub async fn handle_delete_tag(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
+    let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
+    let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
+    let tag_name = args.get("tag_name").and_then(|v| v.as_str()).ok_or("Missing 'tag_name' parameter")?;
+
+    match client.delete_tag(owner, repo, tag_name).await {
+        Ok(_) => Ok(json!({ "status": "success" })),
+        Err(e) => Err(ToolError::gitee("Failed to delete tag", &e)),
+    }
+}
@@ -1,6 +1,6 @@
 use gitee_rs::GiteeClient;
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -8,6 +8,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
             name: "get_user_info".to_string(),
             description: "Get authenticated user profile".to_string(),
             input_schema: json!({ "type": "object", "properties": {} }),
+            annotations: None,
         },
         Tool {
             name: "get_user_detail".to_string(),
@@ -19,6 +20,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["username"]
             }),
+            annotations: None,
         },
         Tool {
             name: "search_users".to_string(),
@@ -30,34 +32,35 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["q"]
             }),
+            annotations: None,
         },
     ]
 }
 
-pub async fn handle_get_authenticated_user(client: &GiteeClient) -> Result<Value, String> {
+pub async fn handle_get_authenticated_user(client: &GiteeClient) -> Result<Value, ToolError> {
     match client.get_authenticated_user().await {
         Ok(user) => Ok(json!({ "user": user })),
-        Err(e) => Err(format!("Failed to get authenticated user info: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get authenticated user info", &e)),
     }
 }
 
-pub async fn handle_get_user_info(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_user_info(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let username = args.get("username").and_then(|v| v.as_str()).ok_or("Missing 'username' parameter")?;
 
     match client.get_user_info(username).await {
         Ok(user) => Ok(json!({ "user": user })),
-        Err(e) => Err(format!("Failed to get user info: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get user info", &e)),
     }
 }
 
-pub async fn handle_search_users(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_search_users(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let query = args.get("q").and_then(|v| v.as_str()).ok_or("Missing 'q' parameter")?;
 
             match client.search_users(query).await {
 
                 Ok(users) => Ok(json!({ "users": users })),
 
-                Err(e) => Err(format!("Failed to search users: {}", e)),
+                Err(e) => Err(ToolError::gitee("Failed to search users", &e)),
 
             }
 
@@ -1,6 +1,6 @@
 use gitee_rs::GiteeClient;
 use serde_json::{json, Value};
-use crate::Tool;
+use crate::{Tool, ToolError};
 
 pub fn get_tool_definitions() -> Vec<Tool> {
     vec![
@@ -15,6 +15,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo"]
             }),
+            annotations: None,
         },
         Tool {
             name: "get_repo_wiki".to_string(),
@@ -28,6 +29,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "slug"]
             }),
+            annotations: None,
         },
         Tool {
             name: "create_repo_wiki".to_string(),
@@ -42,6 +44,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "title", "body"]
             }),
+            annotations: None,
         },
         Tool {
             name: "update_repo_wiki".to_string(),
@@ -57,6 +60,7 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "slug", "title", "body"]
             }),
+            annotations: None,
         },
         Tool {
             name: "delete_repo_wiki".to_string(),
@@ -70,32 +74,33 @@ pub fn get_tool_definitions() -> Vec<Tool> {
                 },
                 "required": ["owner", "repo", "slug"]
             }),
+            annotations: Some(json!({ "destructiveHint": true })),
         },
     ]
 }
 
-pub async fn handle_list_repo_wikis(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_list_repo_wikis(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
 
     match client.list_repo_wikis(owner, repo).await {
         Ok(wikis) => Ok(json!({ "wikis": wikis })),
-        Err(e) => Err(format!("Failed to list wiki pages: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to list wiki pages", &e)),
     }
 }
 
-pub async fn handle_get_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_get_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let slug = args.get("slug").and_then(|v| v.as_str()).ok_or("Missing 'slug' parameter")?;
 
     match client.get_repo_wiki(owner, repo, slug).await {
         Ok(wiki) => Ok(json!({ "wiki": wiki })),
-        Err(e) => Err(format!("Failed to get wiki page: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to get wiki page", &e)),
     }
 }
 
-pub async fn handle_create_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_create_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let title = args.get("title").and_then(|v| v.as_str()).ok_or("Missing 'title' parameter")?;
@@ -103,11 +108,11 @@ pub async fn handle_create_repo_wiki(client: &GiteeClient, args: &Value) -> Resu
 
     match client.create_repo_wiki(owner, repo, title, body).await {
         Ok(wiki) => Ok(json!({ "wiki": wiki })),
-        Err(e) => Err(format!("Failed to create wiki page: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to create wiki page", &e)),
     }
 }
 
-pub async fn handle_update_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_update_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let slug = args.get("slug").and_then(|v| v.as_str()).ok_or("Missing 'slug' parameter")?;
@@ -116,17 +121,17 @@ pub async fn handle_update_repo_wiki(client: &GiteeClient, args: &Value) -> Resu
 
     match client.update_repo_wiki(owner, repo, slug, title, body).await {
         Ok(wiki) => Ok(json!({ "wiki": wiki })),
-        Err(e) => Err(format!("Failed to update wiki page: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to update wiki page", &e)),
     }
 }
 
-pub async fn handle_delete_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, String> {
+pub async fn handle_delete_repo_wiki(client: &GiteeClient, args: &Value) -> Result<Value, ToolError> {
     let owner = args.get("owner").and_then(|v| v.as_str()).ok_or("Missing 'owner' parameter")?;
     let repo = args.get("repo").and_then(|v| v.as_str()).ok_or("Missing 'repo' parameter")?;
     let slug = args.get("slug").and_then(|v| v.as_str()).ok_or("Missing 'slug' parameter")?;
 
     match client.delete_repo_wiki(owner, repo, slug).await {
         Ok(_) => Ok(json!({ "status": "success" })),
-        Err(e) => Err(format!("Failed to delete wiki page: {}", e)),
+        Err(e) => Err(ToolError::gitee("Failed to delete wiki page", &e)),
     }
 }
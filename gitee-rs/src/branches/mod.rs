@@ -0,0 +1,202 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient};
+use reqwest::Method;
+use std::collections::HashMap;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List repository branches
+    pub async fn list_branches(&self, owner: &str, repo: &str) -> Result<Vec<Branch>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let branches: Vec<Branch> = response.json().await?;
+        Ok(branches)
+    }
+
+    /// Paginate over repository branches
+    pub fn paginate_branches(&self, owner: &str, repo: &str) -> Paginator<'_, Branch> {
+        let url = format!("{}/repos/{}/{}/branches", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// Get a branch together with its head commit
+    pub async fn get_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}", self.base_url(), owner, repo, encode_path_segment(branch));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let branch: Branch = response.json().await?;
+        Ok(branch)
+    }
+
+    /// Create a branch from a branch name, tag or commit sha
+    pub async fn create_branch(&self, owner: &str, repo: &str, branch_name: &str, refs: &str) -> Result<Branch, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches", self.base_url(), owner, repo);
+
+        let payload = serde_json::json!({
+            "refs": refs,
+            "branch_name": branch_name,
+        });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let branch: Branch = response.json().await?;
+        Ok(branch)
+    }
+
+    /// Delete a branch
+    pub async fn delete_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}", self.base_url(), owner, repo, encode_path_segment(branch));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Set a branch as protected
+    pub async fn protect_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<Branch, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}/protection", self.base_url(), owner, repo, encode_path_segment(branch));
+        let request = self
+            .request(Method::PUT, &url)
+            .header("Content-Length", 0);
+        let response = self.send(request).await?;
+
+        let branch: Branch = response.json().await?;
+        Ok(branch)
+    }
+
+    /// Remove the protection from a branch
+    pub async fn unprotect_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}/protection", self.base_url(), owner, repo, encode_path_segment(branch));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Create a protection rule for all branches matching a wildcard (e.g. `release/*`).
+    /// `pusher` and `merger` accept "admin", "none" or a comma-separated list of usernames.
+    pub async fn create_protection_rule(&self, owner: &str, repo: &str, wildcard: &str, pusher: Option<&str>, merger: Option<&str>) -> Result<ProtectionRule, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/setting/new", self.base_url(), owner, repo);
+
+        let mut payload = protection_rule_payload(pusher, merger);
+        payload.insert("wildcard", wildcard);
+
+        let request = self
+            .request(Method::PUT, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let rule: ProtectionRule = response.json().await?;
+        Ok(rule)
+    }
+
+    /// Get a wildcard protection rule
+    pub async fn get_protection_rule(&self, owner: &str, repo: &str, wildcard: &str) -> Result<ProtectionRule, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}/setting", self.base_url(), owner, repo, encode_path_segment(wildcard));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let rule: ProtectionRule = response.json().await?;
+        Ok(rule)
+    }
+
+    /// Update a wildcard protection rule; `pusher` and `merger` keep their current value when unset
+    pub async fn update_protection_rule(&self, owner: &str, repo: &str, wildcard: &str, new_wildcard: Option<&str>, pusher: Option<&str>, merger: Option<&str>) -> Result<ProtectionRule, GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}/setting", self.base_url(), owner, repo, encode_path_segment(wildcard));
+
+        // Gitee requires both permissions on every update, so missing ones are read from the rule
+        let current = match (pusher, merger) {
+            (Some(_), Some(_)) => None,
+            _ => Some(self.get_protection_rule(owner, repo, wildcard).await?),
+        };
+        let payload = update_rule_payload(wildcard, new_wildcard, pusher, merger, current.as_ref())?;
+
+        let request = self
+            .request(Method::PUT, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let rule: ProtectionRule = response.json().await?;
+        Ok(rule)
+    }
+
+    /// Delete a wildcard protection rule
+    pub async fn delete_protection_rule(&self, owner: &str, repo: &str, wildcard: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/branches/{}/setting", self.base_url(), owner, repo, encode_path_segment(wildcard));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+/// Who may push and merge under a protection rule; Gitee requires both, so they default to admins
+fn protection_rule_payload<'a>(pusher: Option<&'a str>, merger: Option<&'a str>) -> HashMap<&'static str, &'a str> {
+    let mut payload = HashMap::new();
+    payload.insert("pusher", pusher.unwrap_or("admin"));
+    payload.insert("merger", merger.unwrap_or("admin"));
+    payload
+}
+
+fn update_rule_payload(wildcard: &str, new_wildcard: Option<&str>, pusher: Option<&str>, merger: Option<&str>, current: Option<&ProtectionRule>) -> Result<HashMap<&'static str, String>, GiteeError> {
+    let keep = |given: Option<&str>, existing: fn(&ProtectionRule) -> Option<String>, field: &str| {
+        given
+            .map(|value| value.to_string())
+            .or_else(|| current.and_then(existing))
+            .ok_or_else(|| GiteeError::ApiError(format!("Cannot read the current {} of protection rule '{}'; pass them explicitly", field, wildcard)))
+    };
+
+    let mut payload = HashMap::new();
+    payload.insert("new_wildcard", new_wildcard.unwrap_or(wildcard).to_string());
+    payload.insert("pusher", keep(pusher, ProtectionRule::pusher_setting, "pushers")?);
+    payload.insert("merger", keep(merger, ProtectionRule::merger_setting, "mergers")?);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pushers: serde_json::Value, mergers: serde_json::Value) -> ProtectionRule {
+        serde_json::from_value(serde_json::json!({
+            "id": 1024,
+            "wildcard": "release/*",
+            "pushers": pushers,
+            "mergers": mergers
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_protection_rule_payload_defaults_to_admins() {
+        let payload = protection_rule_payload(None, Some("alice,bob"));
+        assert_eq!(payload["pusher"], "admin");
+        assert_eq!(payload["merger"], "alice,bob");
+    }
+
+    #[test]
+    fn test_rename_keeps_existing_pushers_and_mergers() {
+        let current = rule(serde_json::json!([{ "login": "alice" }, { "login": "bob" }]), serde_json::json!([]));
+        let payload = update_rule_payload("release/*", Some("release-*"), None, None, Some(&current)).unwrap();
+        assert_eq!(payload["new_wildcard"], "release-*");
+        assert_eq!(payload["pusher"], "alice,bob");
+        assert_eq!(payload["merger"], "none");
+
+        let payload = update_rule_payload("release/*", None, None, Some("admin"), Some(&current)).unwrap();
+        assert_eq!(payload["new_wildcard"], "release/*");
+        assert_eq!(payload["pusher"], "alice,bob");
+        assert_eq!(payload["merger"], "admin");
+    }
+
+    #[test]
+    fn test_unreadable_permissions_are_not_reset() {
+        let current = rule(serde_json::json!([{ "access_level": 40 }]), serde_json::json!(["admin"]));
+        let err = update_rule_payload("release/*", Some("release-*"), None, None, Some(&current)).unwrap_err();
+        assert!(err.to_string().contains("pushers"));
+        assert!(update_rule_payload("release/*", None, Some("admin"), None, Some(&current)).is_ok());
+    }
+}
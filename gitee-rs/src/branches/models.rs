@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::commits::CommitDetail;
+use crate::utils::deserialize_string_or_int;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Branch {
+    pub name: String,
+    pub commit: BranchCommit,
+    #[serde(default)]
+    pub protected: bool,
+    #[serde(default)]
+    pub protection_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BranchCommit {
+    pub sha: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub commit: Option<CommitDetail>, // Only returned when fetching a single branch
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ProtectionRule {
+    #[serde(default, deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub wildcard: String,
+    #[serde(default)]
+    pub pushers: Vec<Value>,
+    #[serde(default)]
+    pub mergers: Vec<Value>,
+}
+
+impl ProtectionRule {
+    /// Who may push, in the form the rule endpoints accept ("admin", "none" or comma-separated
+    /// usernames); `None` when Gitee reports the pushers in a shape that cannot be mapped back
+    pub fn pusher_setting(&self) -> Option<String> {
+        permission_setting(&self.pushers)
+    }
+
+    /// Who may merge, in the same form as [`ProtectionRule::pusher_setting`]
+    pub fn merger_setting(&self) -> Option<String> {
+        permission_setting(&self.mergers)
+    }
+}
+
+fn permission_setting(entries: &[Value]) -> Option<String> {
+    if entries.is_empty() {
+        return Some("none".to_string());
+    }
+    let names = entries
+        .iter()
+        .map(|entry| match entry {
+            Value::String(name) => Some(name.clone()),
+            Value::Object(fields) => ["login", "username", "role"]
+                .iter()
+                .find_map(|key| fields.get(*key).and_then(|v| v.as_str()))
+                .map(|name| name.to_string()),
+            _ => None,
+        })
+        .collect::<Option<Vec<String>>>()?;
+    Some(names.join(","))
+}
+
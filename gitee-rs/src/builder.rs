@@ -0,0 +1,126 @@
+use crate::{error::GiteeError, retry::RetryPolicy, GiteeClient};
+use reqwest::{Certificate, Client, Proxy};
+use std::env;
+use std::time::Duration;
+
+/// Default User-Agent sent with every request
+pub const DEFAULT_USER_AGENT: &str = concat!("gitee-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Default Gitee API base URL
+pub const DEFAULT_BASE_URL: &str = "https://gitee.com/api/v5";
+
+/// Builder for [`GiteeClient`]
+///
+/// Token and base URL fall back to the `GITEE_TOKEN` and `GITEE_API_BASE`
+/// environment variables. When a prebuilt `reqwest::Client` is supplied the
+/// timeout, proxy, certificate and user agent settings are ignored.
+#[derive(Debug, Default)]
+pub struct GiteeClientBuilder {
+    token: Option<String>,
+    base_url: Option<String>,
+    http_client: Option<Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    user_agent: Option<String>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+impl GiteeClientBuilder {
+    /// Create a builder with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the access token
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Set the API base URL, e.g. `https://gitee.example.com/api/v5`
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Use a prebuilt HTTP client
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Set the total timeout for each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing connections
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Route all requests through an HTTP(S) proxy
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Trust an additional root certificate in PEM format
+    pub fn add_root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Set the User-Agent header
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the retry policy
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Build the client
+    pub fn build(self) -> Result<GiteeClient, GiteeError> {
+        let token = self.token.or_else(|| env::var("GITEE_TOKEN").ok())
+            .ok_or(GiteeError::TokenNotFound)?;
+
+        let base_url = self.base_url.or_else(|| env::var("GITEE_API_BASE").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder()
+                    .user_agent(self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT));
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(Proxy::all(proxy)?);
+                }
+                for pem in &self.root_certificates {
+                    builder = builder.add_root_certificate(Certificate::from_pem(pem)?);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(GiteeClient::from_parts(
+            client,
+            base_url.trim_end_matches('/').to_string(),
+            token,
+            self.retry_policy.unwrap_or_default(),
+        ))
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List repository collaborators
+    pub async fn list_collaborators(&self, owner: &str, repo: &str) -> Result<Vec<Collaborator>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/collaborators", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let collaborators: Vec<Collaborator> = response.json().await?;
+        Ok(collaborators)
+    }
+
+    /// Paginate over repository collaborators
+    pub fn paginate_collaborators(&self, owner: &str, repo: &str) -> Paginator<'_, Collaborator> {
+        let url = format!("{}/repos/{}/{}/collaborators", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// Check whether a user is a collaborator on the repository
+    pub async fn is_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<bool, GiteeError> {
+        let url = format!("{}/repos/{}/{}/collaborators/{}", self.base_url(), owner, repo, encode_path_segment(username));
+        let request = self.request(Method::GET, &url);
+
+        // Gitee answers 204 for collaborators and 404 for everyone else
+        match self.send(request).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.is_not_found() => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Add a collaborator, or change the permission of an existing one
+    pub async fn add_collaborator(&self, owner: &str, repo: &str, username: &str, permission: Permission) -> Result<Collaborator, GiteeError> {
+        let url = format!("{}/repos/{}/{}/collaborators/{}", self.base_url(), owner, repo, encode_path_segment(username));
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("permission", permission.as_str());
+
+        let request = self
+            .request(Method::PUT, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let collaborator: Collaborator = response.json().await?;
+        Ok(collaborator)
+    }
+
+    /// Remove a collaborator
+    pub async fn remove_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/collaborators/{}", self.base_url(), owner, repo, encode_path_segment(username));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Get a user's permission on the repository
+    pub async fn get_collaborator_permission(&self, owner: &str, repo: &str, username: &str) -> Result<RepoPermission, GiteeError> {
+        let url = format!("{}/repos/{}/{}/collaborators/{}/permission", self.base_url(), owner, repo, encode_path_segment(username));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let permission: RepoPermission = response.json().await?;
+        Ok(permission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collaborator_highest_permission() {
+        let collaborator: Collaborator = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "login": "alice",
+            "name": "Alice",
+            "avatar_url": "",
+            "html_url": "https://gitee.com/alice",
+            "permissions": { "pull": true, "push": true, "admin": false }
+        }))
+        .unwrap();
+
+        assert_eq!(collaborator.id, "42");
+        assert_eq!(collaborator.permission(), Some(Permission::Push));
+        assert_eq!(Permission::parse("ADMIN"), Some(Permission::Admin));
+        assert_eq!(Permission::parse("owner"), None);
+    }
+}
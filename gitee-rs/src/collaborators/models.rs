@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use crate::users::User;
+use crate::utils::deserialize_string_or_int;
+
+/// Access level that can be granted to a collaborator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    /// Read-only access
+    Pull,
+    /// Read and write access
+    Push,
+    /// Full control, including settings and members
+    Admin,
+}
+
+impl Permission {
+    /// Parse `pull`, `push` or `admin`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "pull" => Some(Permission::Pull),
+            "push" => Some(Permission::Push),
+            "admin" => Some(Permission::Admin),
+            _ => None,
+        }
+    }
+
+    /// The name Gitee uses for this permission
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Permission::Pull => "pull",
+            Permission::Push => "push",
+            Permission::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Permissions {
+    #[serde(default)]
+    pub pull: bool,
+    #[serde(default)]
+    pub push: bool,
+    #[serde(default)]
+    pub admin: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Collaborator {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub login: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub permissions: Permissions,
+}
+
+impl Collaborator {
+    /// The highest access level the collaborator holds
+    pub fn permission(&self) -> Option<Permission> {
+        if self.permissions.admin {
+            Some(Permission::Admin)
+        } else if self.permissions.push {
+            Some(Permission::Push)
+        } else if self.permissions.pull {
+            Some(Permission::Pull)
+        } else {
+            None
+        }
+    }
+}
+
+/// A user's effective permission on a repository
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RepoPermission {
+    pub permission: String, // e.g. "admin", "write", "read" or "none"
+    #[serde(default)]
+    pub user: Option<User>,
+}
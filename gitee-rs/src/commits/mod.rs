@@ -0,0 +1,91 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List commits, optionally filtered by ref, path, author and time range
+    pub async fn list_commits(&self, owner: &str, repo: &str, options: Option<CommitListOptions>) -> Result<Vec<Commit>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/commits", self.base_url(), owner, repo);
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let commits: Vec<Commit> = response.json().await?;
+        Ok(commits)
+    }
+
+    /// Paginate over repository commits
+    pub fn paginate_commits(&self, owner: &str, repo: &str, options: Option<CommitListOptions>) -> Paginator<'_, Commit> {
+        let url = format!("{}/repos/{}/{}/commits", self.base_url(), owner, repo);
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// Get a single commit with its stats and changed files
+    pub async fn get_commit(&self, owner: &str, repo: &str, sha: &str) -> Result<Commit, GiteeError> {
+        let url = format!("{}/repos/{}/{}/commits/{}", self.base_url(), owner, repo, encode_path_segment(sha));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let commit: Commit = response.json().await?;
+        Ok(commit)
+    }
+
+    /// Compare two refs (branches, tags or commit shas)
+    pub async fn compare_commits(&self, owner: &str, repo: &str, base: &str, head: &str) -> Result<Compare, GiteeError> {
+        let url = format!(
+            "{}/repos/{}/{}/compare/{}...{}",
+            self.base_url(),
+            owner,
+            repo,
+            encode_path_segment(base),
+            encode_path_segment(head)
+        );
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let compare: Compare = response.json().await?;
+        Ok(compare)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_deserializes_commits_and_files() {
+        let body = serde_json::json!({
+            "base_commit": { "sha": "1111111aaaa", "commit": { "message": "base" } },
+            "merge_base_commit": { "sha": "1111111aaaa", "commit": { "message": "base" } },
+            "commits": [{
+                "sha": "2222222bbbb",
+                "commit": {
+                    "message": "Fix parser\n\nLonger description",
+                    "author": { "name": "dev", "email": "dev@example.com", "date": "2026-01-01T00:00:00+08:00" }
+                },
+                "author": null,
+                "parents": [{ "sha": "1111111aaaa" }]
+            }],
+            "files": [{
+                "filename": "src/lib.rs",
+                "status": "modified",
+                "additions": 3,
+                "deletions": 1,
+                "changes": 4,
+                "patch": "@@ -1 +1 @@"
+            }]
+        });
+
+        let compare: Compare = serde_json::from_value(body).unwrap();
+        assert_eq!(compare.commits.len(), 1);
+        assert_eq!(compare.commits[0].summary(), "Fix parser");
+        assert_eq!(compare.commits[0].parents[0].sha, "1111111aaaa");
+        assert_eq!(compare.files[0].patch.as_deref(), Some("@@ -1 +1 @@"));
+    }
+}
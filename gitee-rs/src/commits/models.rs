@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use crate::users::User;
+use crate::pulls::FileDiff;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Commit {
+    pub sha: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    pub commit: CommitDetail,
+    #[serde(default)]
+    pub author: Option<User>, // Gitee account of the author, if linked
+    #[serde(default)]
+    pub committer: Option<User>,
+    #[serde(default)]
+    pub parents: Vec<CommitRef>,
+    #[serde(default)]
+    pub stats: Option<CommitStats>, // Only returned when fetching a single commit
+    #[serde(default)]
+    pub files: Vec<FileDiff>,
+}
+
+impl Commit {
+    /// First line of the commit message
+    pub fn summary(&self) -> &str {
+        self.commit.message.as_deref().unwrap_or_default().lines().next().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitDetail {
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub author: Option<GitActor>,
+    #[serde(default)]
+    pub committer: Option<GitActor>,
+    #[serde(default)]
+    pub tree: Option<CommitRef>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitRef {
+    pub sha: String,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitActor {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CommitStats {
+    #[serde(default)]
+    pub additions: i32,
+    #[serde(default)]
+    pub deletions: i32,
+    #[serde(default)]
+    pub total: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Compare {
+    #[serde(default)]
+    pub base_commit: Option<Commit>,
+    #[serde(default)]
+    pub merge_base_commit: Option<Commit>,
+    #[serde(default)]
+    pub commits: Vec<Commit>,
+    #[serde(default)]
+    pub files: Vec<FileDiff>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CommitListOptions {
+    pub sha: Option<String>, // Branch name or commit sha to start listing from
+    pub path: Option<String>,
+    pub author: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
@@ -0,0 +1,151 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient, Issue, Label, PullRequest, Repository};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// Get an enterprise
+    pub async fn get_enterprise(&self, enterprise: &str) -> Result<Enterprise, GiteeError> {
+        let url = format!("{}/enterprises/{}", self.base_url(), encode_path_segment(enterprise));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let enterprise: Enterprise = response.json().await?;
+        Ok(enterprise)
+    }
+
+    /// List enterprise members, optionally filtered by role
+    pub async fn list_enterprise_members(&self, enterprise: &str, options: Option<EnterpriseMemberListOptions>) -> Result<Vec<EnterpriseMember>, GiteeError> {
+        let url = format!("{}/enterprises/{}/members", self.base_url(), encode_path_segment(enterprise));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let members: Vec<EnterpriseMember> = response.json().await?;
+        Ok(members)
+    }
+
+    /// Paginate over enterprise members
+    pub fn paginate_enterprise_members(&self, enterprise: &str, options: Option<EnterpriseMemberListOptions>) -> Paginator<'_, EnterpriseMember> {
+        let url = format!("{}/enterprises/{}/members", self.base_url(), encode_path_segment(enterprise));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List enterprise repositories
+    pub async fn list_enterprise_repos(&self, enterprise: &str, options: Option<EnterpriseRepoListOptions>) -> Result<Vec<Repository>, GiteeError> {
+        let url = format!("{}/enterprises/{}/repos", self.base_url(), encode_path_segment(enterprise));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let repos: Vec<Repository> = response.json().await?;
+        Ok(repos)
+    }
+
+    /// Paginate over enterprise repositories
+    pub fn paginate_enterprise_repos(&self, enterprise: &str, options: Option<EnterpriseRepoListOptions>) -> Paginator<'_, Repository> {
+        let url = format!("{}/enterprises/{}/repos", self.base_url(), encode_path_segment(enterprise));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List issues across every repository of an enterprise
+    pub async fn list_enterprise_issues(&self, enterprise: &str, options: Option<EnterpriseIssueListOptions>) -> Result<Vec<Issue>, GiteeError> {
+        let url = format!("{}/enterprises/{}/issues", self.base_url(), encode_path_segment(enterprise));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let issues: Vec<Issue> = response.json().await?;
+        Ok(issues)
+    }
+
+    /// Paginate over enterprise issues
+    pub fn paginate_enterprise_issues(&self, enterprise: &str, options: Option<EnterpriseIssueListOptions>) -> Paginator<'_, Issue> {
+        let url = format!("{}/enterprises/{}/issues", self.base_url(), encode_path_segment(enterprise));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List pull requests across every repository of an enterprise
+    pub async fn list_enterprise_pulls(&self, enterprise: &str, options: Option<EnterprisePullListOptions>) -> Result<Vec<PullRequest>, GiteeError> {
+        let url = format!("{}/enterprises/{}/pull_requests", self.base_url(), encode_path_segment(enterprise));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let pulls: Vec<PullRequest> = response.json().await?;
+        Ok(pulls)
+    }
+
+    /// Paginate over enterprise pull requests
+    pub fn paginate_enterprise_pulls(&self, enterprise: &str, options: Option<EnterprisePullListOptions>) -> Paginator<'_, PullRequest> {
+        let url = format!("{}/enterprises/{}/pull_requests", self.base_url(), encode_path_segment(enterprise));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List labels shared across an enterprise
+    pub async fn list_enterprise_labels(&self, enterprise: &str) -> Result<Vec<Label>, GiteeError> {
+        let url = format!("{}/enterprises/{}/labels", self.base_url(), encode_path_segment(enterprise));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let labels: Vec<Label> = response.json().await?;
+        Ok(labels)
+    }
+
+    /// Get a single enterprise label by name
+    pub async fn get_enterprise_label(&self, enterprise: &str, name: &str) -> Result<Label, GiteeError> {
+        let url = format!("{}/enterprises/{}/labels/{}", self.base_url(), encode_path_segment(enterprise), encode_path_segment(name));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let label: Label = response.json().await?;
+        Ok(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enterprise_issue_carries_type_and_repository() {
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": 1001,
+            "number": "I7ABCD",
+            "title": "Crash on startup",
+            "body": null,
+            "state": "progressing",
+            "html_url": "https://gitee.com/acme/app/issues/I7ABCD",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "updated_at": "2026-01-01T00:00:00+08:00",
+            "issue_type": "缺陷",
+            "issue_state": "修复中",
+            "repository": { "id": 55, "full_name": "acme/app", "human_name": "Acme/App", "html_url": "https://gitee.com/acme/app" }
+        }))
+        .unwrap();
+
+        assert_eq!(issue.issue_type.as_deref(), Some("缺陷"));
+        assert_eq!(issue.repository.as_ref().map(|r| r.full_name.as_str()), Some("acme/app"));
+        assert_eq!(EnterpriseIssueState::parse(&issue.state), Some(EnterpriseIssueState::Progressing));
+
+        let options = EnterpriseIssueListOptions { state: Some(EnterpriseIssueState::Rejected), ..Default::default() };
+        assert_eq!(serde_json::to_value(&options).unwrap()["state"], serde_json::json!("rejected"));
+    }
+}
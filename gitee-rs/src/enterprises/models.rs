@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use crate::users::User;
+use crate::utils::deserialize_string_or_int;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Enterprise {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub path: String, // Path used in URLs, e.g. `/enterprises/{path}`
+    pub name: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EnterpriseMember {
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub remark: Option<String>, // Display name set by the enterprise
+    pub role: String, // "admin", "member" or "viewer"
+    #[serde(default)]
+    pub outsourced: bool,
+    pub user: User,
+}
+
+/// Issue states available in enterprise workspaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EnterpriseIssueState {
+    Open,
+    Progressing,
+    Closed,
+    Rejected,
+    All,
+}
+
+impl EnterpriseIssueState {
+    /// Parse `open`, `progressing`, `closed`, `rejected` or `all`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "open" => Some(EnterpriseIssueState::Open),
+            "progressing" => Some(EnterpriseIssueState::Progressing),
+            "closed" => Some(EnterpriseIssueState::Closed),
+            "rejected" => Some(EnterpriseIssueState::Rejected),
+            "all" => Some(EnterpriseIssueState::All),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct EnterpriseMemberListOptions {
+    pub role: Option<String>, // "all", "admin" or "member"
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct EnterpriseRepoListOptions {
+    pub search: Option<String>,
+    #[serde(rename = "type")]
+    pub repo_type: Option<String>, // "all", "public", "internal" or "private"
+    pub direct: Option<bool>, // Only repositories owned directly by the enterprise
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct EnterpriseIssueListOptions {
+    pub state: Option<EnterpriseIssueState>,
+    pub labels: Option<String>,
+    pub sort: Option<String>,
+    pub direction: Option<String>,
+    pub since: Option<String>,
+    pub milestone: Option<String>,
+    pub assignee: Option<String>,
+    pub creator: Option<String>,
+    pub program: Option<String>,
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct EnterprisePullListOptions {
+    pub repo: Option<String>, // Repository path within the enterprise
+    pub state: Option<String>, // "open", "closed", "merged" or "all"
+    pub head: Option<String>,
+    pub base: Option<String>,
+    pub sort: Option<String>,
+    pub direction: Option<String>,
+    pub labels: Option<String>,
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
@@ -1,4 +1,5 @@
-use reqwest;
+use reqwest::{self, StatusCode};
+use serde_json::Value;
 use std::fmt;
 
 #[derive(Debug)]
@@ -8,6 +9,139 @@ pub enum GiteeError {
     ParseError(serde_json::Error),
     ApiError(String),
     NetworkError(String),
+    /// The server answered with a non-success status
+    Http(Box<HttpError>),
+    /// Content returned by the server could not be decoded (e.g. invalid base64 or UTF-8)
+    DecodeError(String),
+    /// Writing downloaded data failed
+    IoError(std::io::Error),
+    /// An incoming webhook delivery failed its token or signature check
+    WebhookVerification(String),
+}
+
+/// Details of a failed Gitee API call
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    pub status: StatusCode,
+    pub method: String,
+    /// Request path and query, with credentials redacted
+    pub path: String,
+    /// The `message` reported by Gitee, if any
+    pub message: Option<String>,
+    /// The `errors` payload reported by Gitee for validation failures
+    pub errors: Option<Value>,
+    /// Whether the rate limit was exhausted when the error occurred
+    pub rate_limited: bool,
+}
+
+impl HttpError {
+    /// Build the error from a failed response, consuming its body
+    pub(crate) async fn from_response(method: &reqwest::Method, response: reqwest::Response) -> Self {
+        let status = response.status();
+        let path = redacted_path(response.url());
+        let rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+            || crate::retry::RateLimit::from_headers(response.headers())
+                .and_then(|r| r.remaining)
+                == Some(0);
+
+        let body = response.text().await.unwrap_or_default();
+        let (message, errors) = match serde_json::from_str::<Value>(&body) {
+            Ok(Value::Object(mut map)) => {
+                let message = ["message", "error_description", "error"]
+                    .iter()
+                    .find_map(|key| map.get(*key).and_then(|v| v.as_str()).map(str::to_string));
+                (message, map.remove("errors"))
+            }
+            _ => {
+                let text = body.trim();
+                let message = (!text.is_empty()).then(|| text.chars().take(200).collect());
+                (message, None)
+            }
+        };
+
+        HttpError {
+            status,
+            method: method.to_string(),
+            path,
+            message,
+            errors,
+            rate_limited,
+        }
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} {})", self.status, self.method, self.path)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        if let Some(errors) = &self.errors {
+            write!(f, " {}", errors)?;
+        }
+        Ok(())
+    }
+}
+
+fn redacted_path(url: &reqwest::Url) -> String {
+    let mut path = url.path().to_string();
+    let pairs: Vec<String> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let sensitive = ["access_token", "token", "password"].contains(&key.as_ref());
+            format!("{}={}", key, if sensitive { "[REDACTED]" } else { value.as_ref() })
+        })
+        .collect();
+    if !pairs.is_empty() {
+        path.push('?');
+        path.push_str(&pairs.join("&"));
+    }
+    path
+}
+
+impl GiteeError {
+    /// Details of the failed API call, if the server answered with an error status
+    pub fn http(&self) -> Option<&HttpError> {
+        match self {
+            GiteeError::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// HTTP status returned by the server, if any
+    pub fn status(&self) -> Option<StatusCode> {
+        self.http().map(|err| err.status)
+    }
+
+    /// The resource does not exist (or is hidden from the token)
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// The request was rejected because the rate limit was exhausted
+    pub fn is_rate_limited(&self) -> bool {
+        self.http().is_some_and(|err| err.rate_limited)
+    }
+
+    /// The token is missing, invalid or lacks permission
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            GiteeError::TokenNotFound => true,
+            GiteeError::Http(err) => {
+                err.status == StatusCode::UNAUTHORIZED
+                    || (err.status == StatusCode::FORBIDDEN && !err.rate_limited)
+            }
+            _ => false,
+        }
+    }
+
+    /// The request parameters were rejected
+    pub fn is_validation_error(&self) -> bool {
+        matches!(
+            self.status(),
+            Some(StatusCode::BAD_REQUEST) | Some(StatusCode::UNPROCESSABLE_ENTITY)
+        )
+    }
 }
 
 impl fmt::Display for GiteeError {
@@ -18,6 +152,10 @@ impl fmt::Display for GiteeError {
             GiteeError::ParseError(err) => write!(f, "Parse error: {}", err),
             GiteeError::ApiError(msg) => write!(f, "API error: {}", msg),
             GiteeError::NetworkError(msg) => write!(f, "Network error: {}", msg),
+            GiteeError::Http(err) => write!(f, "API error: {}", err),
+            GiteeError::DecodeError(msg) => write!(f, "Decode error: {}", msg),
+            GiteeError::IoError(err) => write!(f, "IO error: {}", err),
+            GiteeError::WebhookVerification(msg) => write!(f, "Webhook verification failed: {}", msg),
         }
     }
 }
@@ -34,4 +172,59 @@ impl From<serde_json::Error> for GiteeError {
     fn from(err: serde_json::Error) -> Self {
         GiteeError::ParseError(err)
     }
-}
\ No newline at end of file
+}
+
+impl From<std::io::Error> for GiteeError {
+    fn from(err: std::io::Error) -> Self {
+        GiteeError::IoError(err)
+    }
+}
+
+impl From<HttpError> for GiteeError {
+    fn from(err: HttpError) -> Self {
+        GiteeError::Http(Box::new(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn http_error(status: StatusCode, rate_limited: bool) -> GiteeError {
+        HttpError {
+            status,
+            method: "GET".to_string(),
+            path: "/api/v5/repos/owner/repo".to_string(),
+            message: Some("Not Found Project".to_string()),
+            errors: None,
+            rate_limited,
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_error_predicates() {
+        assert!(http_error(StatusCode::NOT_FOUND, false).is_not_found());
+        assert!(http_error(StatusCode::UNAUTHORIZED, false).is_auth_error());
+        assert!(http_error(StatusCode::FORBIDDEN, false).is_auth_error());
+        assert!(!http_error(StatusCode::FORBIDDEN, true).is_auth_error());
+        assert!(http_error(StatusCode::FORBIDDEN, true).is_rate_limited());
+        assert!(http_error(StatusCode::UNPROCESSABLE_ENTITY, false).is_validation_error());
+        assert!(!GiteeError::ApiError("boom".to_string()).is_not_found());
+    }
+
+    #[test]
+    fn test_error_display_includes_request_context() {
+        let err = http_error(StatusCode::NOT_FOUND, false);
+        assert_eq!(
+            err.to_string(),
+            "API error: 404 Not Found (GET /api/v5/repos/owner/repo): Not Found Project"
+        );
+    }
+
+    #[test]
+    fn test_credentials_are_redacted_from_path() {
+        let url = reqwest::Url::parse("https://gitee.com/api/v5/user?access_token=secret&page=2").unwrap();
+        assert_eq!(redacted_path(&url), "/api/v5/user?access_token=[REDACTED]&page=2");
+    }
+}
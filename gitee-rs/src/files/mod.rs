@@ -1,6 +1,8 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{error::GiteeError, utils::{encode_file_path, encode_path_segment, write_body}, GiteeClient};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use reqwest::Method;
 use serde::Deserialize;
+use tokio::io::AsyncWrite;
 
 mod models;
 pub use models::*;
@@ -9,26 +11,103 @@ impl GiteeClient {
     /// Get file content
     pub async fn get_file_content(&self, owner: &str, repo: &str, file_path: &str, r#ref: Option<&str>) -> Result<FileContent, GiteeError> {
         let url = format!("{}/repos/{}/{}/contents/{}", self.base_url(), owner, repo, file_path);
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(r) = r#ref {
             request = request.query(&[("ref", r)]);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get file content: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let file_content: FileContent = response.json().await?;
         Ok(file_content)
     }
 
+    /// Get the README the repository shows on its home page, at `ref` or the default branch.
+    /// The content is left encoded; see [`GiteeClient::get_readme_text`] for the text.
+    pub async fn get_readme(&self, owner: &str, repo: &str, r#ref: Option<&str>) -> Result<FileContent, GiteeError> {
+        let url = format!("{}/repos/{}/{}/readme", self.base_url(), owner, repo);
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(r) = r#ref {
+            request = request.query(&[("ref", r)]);
+        }
+
+        let response = self.send(request).await?;
+
+        let readme: FileContent = response.json().await?;
+        Ok(readme)
+    }
+
+    /// Get the README at `ref` or the default branch as decoded UTF-8 text
+    pub async fn get_readme_text(&self, owner: &str, repo: &str, r#ref: Option<&str>) -> Result<Readme, GiteeError> {
+        let readme = self.get_readme(owner, repo, r#ref).await?;
+        let text = readme.decoded_text()?;
+        Ok(Readme { path: readme.path, html_url: readme.html_url, text })
+    }
+
+    /// Render Markdown to HTML with Gitee's renderer
+    pub async fn render_markdown(&self, text: &str) -> Result<String, GiteeError> {
+        let url = format!("{}/markdown", self.base_url());
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("text", text);
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let body = response.text().await?;
+        Ok(unquote_html(body))
+    }
+
+    /// Get the raw bytes of a file without the JSON/base64 round trip
+    pub async fn get_raw_file(&self, owner: &str, repo: &str, file_path: &str, r#ref: Option<&str>) -> Result<Vec<u8>, GiteeError> {
+        let mut buffer = Vec::new();
+        self.download_raw_file(owner, repo, file_path, r#ref, &mut buffer).await?;
+        Ok(buffer)
+    }
+
+    /// Stream the raw content of a file into `writer`, returning the number of bytes written.
+    /// Suitable for large or binary files.
+    pub async fn download_raw_file<W: AsyncWrite + Unpin>(&self, owner: &str, repo: &str, file_path: &str, r#ref: Option<&str>, writer: &mut W) -> Result<u64, GiteeError> {
+        let url = format!("{}/repos/{}/{}/raw/{}", self.base_url(), owner, repo, encode_file_path(file_path));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(r) = r#ref {
+            request = request.query(&[("ref", r)]);
+        }
+
+        let response = self.send(request).await?;
+        write_body(response, writer).await
+    }
+
+    /// Get the git tree of a commit, branch or tag; `recursive` lists every nested entry in one call
+    pub async fn get_tree(&self, owner: &str, repo: &str, sha: &str, recursive: bool) -> Result<Tree, GiteeError> {
+        let url = format!("{}/repos/{}/{}/git/trees/{}", self.base_url(), owner, repo, encode_path_segment(sha));
+        let mut request = self.request(Method::GET, &url);
+
+        if recursive {
+            request = request.query(&[("recursive", 1)]);
+        }
+
+        let response = self.send(request).await?;
+
+        let tree: Tree = response.json().await?;
+        Ok(tree)
+    }
+
+    /// Get a git blob by sha
+    pub async fn get_blob(&self, owner: &str, repo: &str, sha: &str) -> Result<Blob, GiteeError> {
+        let url = format!("{}/repos/{}/{}/git/blobs/{}", self.base_url(), owner, repo, encode_path_segment(sha));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let blob: Blob = response.json().await?;
+        Ok(blob)
+    }
+
     /// List repository files
     pub async fn list_repo_files(&self, owner: &str, repo: &str, path: Option<&str>, r#ref: Option<&str>) -> Result<Vec<RepoFile>, GiteeError> {
         let url = if let Some(p) = path {
@@ -41,21 +120,13 @@ impl GiteeClient {
             format!("{}/repos/{}/{}/contents", self.base_url(), owner, repo)
         };
 
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(r) = r#ref {
             request = request.query(&[("ref", r)]);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list repo files: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let repo_files: Vec<RepoFile> = response.json().await?;
         Ok(repo_files)
@@ -71,20 +142,10 @@ impl GiteeClient {
         if let Some(p) = page { params.push(("page", p.to_string())); }
         if let Some(pp) = per_page { params.push(("per_page", pp.to_string())); }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .query(&params)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to search files by content: {}",
-                response.status()
-            )));
-        }
+            .query(&params);
+        let response = self.send(request).await?;
 
         #[derive(Deserialize)]
         struct SearchCodeResult {
@@ -94,4 +155,110 @@ impl GiteeClient {
         let search_result: SearchCodeResult = response.json().await?;
         Ok(search_result.items)
     }
+
+    /// Create a file, committing the given content (encoded to base64 by the client)
+    pub async fn create_file(&self, owner: &str, repo: &str, file_path: &str, content: impl AsRef<[u8]>, message: &str, options: Option<FileWriteOptions>) -> Result<FileCommit, GiteeError> {
+        let url = format!("{}/repos/{}/{}/contents/{}", self.base_url(), owner, repo, encode_file_path(file_path));
+
+        let mut payload = serde_json::to_value(options.unwrap_or_default())?;
+        payload["content"] = STANDARD.encode(content).into();
+        payload["message"] = message.into();
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let file_commit: FileCommit = response.json().await?;
+        Ok(file_commit)
+    }
+
+    /// Update a file; `sha` is the blob sha of the current version, as returned in `FileContent`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_file(&self, owner: &str, repo: &str, file_path: &str, content: impl AsRef<[u8]>, sha: &str, message: &str, options: Option<FileWriteOptions>) -> Result<FileCommit, GiteeError> {
+        let url = format!("{}/repos/{}/{}/contents/{}", self.base_url(), owner, repo, encode_file_path(file_path));
+
+        let mut payload = serde_json::to_value(options.unwrap_or_default())?;
+        payload["content"] = STANDARD.encode(content).into();
+        payload["sha"] = sha.into();
+        payload["message"] = message.into();
+
+        let request = self
+            .request(Method::PUT, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let file_commit: FileCommit = response.json().await?;
+        Ok(file_commit)
+    }
+
+    /// Delete a file; `sha` is the blob sha of the current version
+    pub async fn delete_file(&self, owner: &str, repo: &str, file_path: &str, sha: &str, message: &str, options: Option<FileWriteOptions>) -> Result<FileCommit, GiteeError> {
+        let url = format!("{}/repos/{}/{}/contents/{}", self.base_url(), owner, repo, encode_file_path(file_path));
+
+        let mut payload = serde_json::to_value(options.unwrap_or_default())?;
+        payload["sha"] = sha.into();
+        payload["message"] = message.into();
+
+        let request = self
+            .request(Method::DELETE, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let file_commit: FileCommit = response.json().await?;
+        Ok(file_commit)
+    }
+}
+
+/// The markdown endpoint answers with bare HTML, though some deployments wrap it in a JSON string
+fn unquote_html(body: String) -> String {
+    if body.starts_with('"') {
+        if let Ok(html) = serde_json::from_str::<String>(&body) {
+            return html;
+        }
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_content(content: &str, encoding: Option<&str>) -> FileContent {
+        FileContent {
+            name: "README.md".to_string(),
+            path: "docs/README.md".to_string(),
+            sha: "abc".to_string(),
+            size: None,
+            url: String::new(),
+            html_url: String::new(),
+            git_url: String::new(),
+            download_url: String::new(),
+            file_type: "file".to_string(),
+            content: Some(content.to_string()),
+            encoding: encoding.map(|e| e.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_decodes_line_wrapped_base64() {
+        let file = file_content("5L2g5aW9\nLCBHaXRl\nZQ==\n", Some("base64"));
+        assert_eq!(file.decoded_text().unwrap(), "你好, Gitee");
+    }
+
+    #[test]
+    fn test_decode_errors_are_reported() {
+        let invalid = file_content("not base64!", Some("base64"));
+        assert!(matches!(invalid.decoded_bytes(), Err(GiteeError::DecodeError(_))));
+
+        let binary = file_content("/w==", Some("base64"));
+        assert_eq!(binary.decoded_bytes().unwrap(), vec![0xff]);
+        assert!(matches!(binary.decoded_text(), Err(GiteeError::DecodeError(_))));
+    }
+
+    #[test]
+    fn test_rendered_markdown_is_unquoted() {
+        assert_eq!(unquote_html("<h1>Title</h1>".to_string()), "<h1>Title</h1>");
+        assert_eq!(unquote_html("\"<p>a \\\"b\\\"</p>\\n\"".to_string()), "<p>a \"b\"</p>\n");
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use crate::utils::deserialize_string_or_int;
+use crate::commits::{CommitRef, GitActor};
+use crate::error::GiteeError;
+use crate::utils::{decode_content, decode_text, deserialize_string_or_int};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FileContent {
@@ -23,6 +25,26 @@ pub struct FileContent {
     pub encoding: Option<String>,
 }
 
+impl FileContent {
+    /// Decode `content` into raw bytes according to `encoding`
+    pub fn decoded_bytes(&self) -> Result<Vec<u8>, GiteeError> {
+        decode_content(self.content.as_deref(), self.encoding.as_deref(), &self.path)
+    }
+
+    /// Decode `content` into UTF-8 text
+    pub fn decoded_text(&self) -> Result<String, GiteeError> {
+        decode_text(self.decoded_bytes()?, &self.path)
+    }
+}
+
+/// A repository README with its content decoded
+#[derive(Debug, Clone, Serialize)]
+pub struct Readme {
+    pub path: String, // e.g. "README.md" or "docs/README.zh-CN.md"
+    pub html_url: String,
+    pub text: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RepoFile {
     pub name: String,
@@ -41,3 +63,105 @@ pub struct RepoFile {
     #[serde(rename = "type")]
     pub file_type: String, // "file", "dir"
 }
+
+/// Name and email recorded as the author or committer of a file change
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitIdentity {
+    pub name: String,
+    pub email: String,
+}
+
+/// Optional settings for creating, updating or deleting a file
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FileWriteOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>, // Defaults to the repository's default branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<CommitIdentity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<CommitIdentity>,
+}
+
+/// Result of a file write: the new file metadata and the commit that was created
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileCommit {
+    #[serde(default)]
+    pub content: Option<RepoFile>, // None after a delete
+    pub commit: FileCommitInfo,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileCommitInfo {
+    pub sha: String,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub author: Option<GitActor>,
+    #[serde(default)]
+    pub committer: Option<GitActor>,
+    #[serde(default)]
+    pub tree: Option<CommitRef>,
+    #[serde(default)]
+    pub parents: Vec<CommitRef>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tree {
+    pub sha: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub tree: Vec<TreeEntry>,
+    #[serde(default)]
+    pub truncated: bool, // Set when the server cut the listing short
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: String,
+    #[serde(rename = "type")]
+    pub entry_type: String, // "blob", "tree", "commit" (submodule)
+    pub sha: String,
+    #[serde(default)]
+    pub size: Option<u64>, // Only set for blobs
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+impl TreeEntry {
+    /// Whether the entry is a file
+    pub fn is_blob(&self) -> bool {
+        self.entry_type == "blob"
+    }
+
+    /// Whether the entry is a directory
+    pub fn is_tree(&self) -> bool {
+        self.entry_type == "tree"
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Blob {
+    pub sha: String,
+    #[serde(default)]
+    pub size: Option<u64>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub encoding: Option<String>,
+}
+
+impl Blob {
+    /// Decode `content` into raw bytes according to `encoding`
+    pub fn decoded_bytes(&self) -> Result<Vec<u8>, GiteeError> {
+        decode_content(self.content.as_deref(), self.encoding.as_deref(), &self.sha)
+    }
+
+    /// Decode `content` into UTF-8 text
+    pub fn decoded_text(&self) -> Result<String, GiteeError> {
+        decode_text(self.decoded_bytes()?, &self.sha)
+    }
+}
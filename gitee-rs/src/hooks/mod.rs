@@ -0,0 +1,152 @@
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+/// Build the create/update payload, falling back to `current` for unset event toggles
+fn hook_payload(url: &str, options: &HookOptions, current: Option<&Hook>) -> serde_json::Value {
+    let mut payload = serde_json::json!({ "url": url });
+
+    let events = [
+        ("push_events", options.push_events, current.map(|h| h.push_events)),
+        ("tag_push_events", options.tag_push_events, current.map(|h| h.tag_push_events)),
+        ("issues_events", options.issues_events, current.map(|h| h.issues_events)),
+        ("note_events", options.note_events, current.map(|h| h.note_events)),
+        ("merge_requests_events", options.merge_requests_events, current.map(|h| h.merge_requests_events)),
+    ];
+    for (key, wanted, existing) in events {
+        if let Some(enabled) = wanted.or(existing) {
+            payload[key] = serde_json::json!(enabled);
+        }
+    }
+
+    match &options.auth {
+        Some(HookAuth::Password(password)) => {
+            payload["encryption_type"] = serde_json::json!(0);
+            payload["password"] = serde_json::json!(password);
+        }
+        Some(HookAuth::SignKey(key)) => {
+            payload["encryption_type"] = serde_json::json!(1);
+            payload["password"] = serde_json::json!(key);
+        }
+        None => {}
+    }
+
+    payload
+}
+
+impl GiteeClient {
+    /// List repository webhooks
+    pub async fn list_hooks(&self, owner: &str, repo: &str) -> Result<Vec<Hook>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/hooks", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let hooks: Vec<Hook> = response.json().await?;
+        Ok(hooks)
+    }
+
+    /// Paginate over repository webhooks
+    pub fn paginate_hooks(&self, owner: &str, repo: &str) -> Paginator<'_, Hook> {
+        let url = format!("{}/repos/{}/{}/hooks", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// Get a repository webhook
+    pub async fn get_hook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<Hook, GiteeError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base_url(), owner, repo, hook_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let hook: Hook = response.json().await?;
+        Ok(hook)
+    }
+
+    /// Create a repository webhook delivering to `hook_url`
+    pub async fn create_hook(&self, owner: &str, repo: &str, hook_url: &str, options: Option<HookOptions>) -> Result<Hook, GiteeError> {
+        let url = format!("{}/repos/{}/{}/hooks", self.base_url(), owner, repo);
+        let payload = hook_payload(hook_url, &options.unwrap_or_default(), None);
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let hook: Hook = response.json().await?;
+        Ok(hook)
+    }
+
+    /// Update a repository webhook; a `None` URL and unset options keep their current value
+    pub async fn update_hook(&self, owner: &str, repo: &str, hook_id: &str, hook_url: Option<&str>, options: Option<HookOptions>) -> Result<Hook, GiteeError> {
+        let current = self.get_hook(owner, repo, hook_id).await?;
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base_url(), owner, repo, hook_id);
+        let payload = hook_payload(hook_url.unwrap_or(&current.url), &options.unwrap_or_default(), Some(&current));
+
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let hook: Hook = response.json().await?;
+        Ok(hook)
+    }
+
+    /// Delete a repository webhook
+    pub async fn delete_hook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}", self.base_url(), owner, repo, hook_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Ask Gitee to send a test push delivery to the webhook
+    pub async fn test_hook(&self, owner: &str, repo: &str, hook_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/hooks/{}/tests", self.base_url(), owner, repo, hook_id);
+        let request = self.request(Method::POST, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_payload_merges_current_events() {
+        let current: Hook = serde_json::from_value(serde_json::json!({
+            "id": 12,
+            "url": "https://ci.example.com/gitee",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "password": "",
+            "project_id": 99,
+            "result": "ok",
+            "result_code": 200,
+            "push_events": true,
+            "tag_push_events": false,
+            "issues_events": true,
+            "note_events": false,
+            "merge_requests_events": false
+        }))
+        .unwrap();
+        assert_eq!(current.events(), vec![HookEvent::Push, HookEvent::Issues]);
+
+        let options = HookOptions {
+            auth: Some(HookAuth::SignKey("s3cret".to_string())),
+            merge_requests_events: Some(true),
+            ..Default::default()
+        };
+        let payload = hook_payload(&current.url, &options, Some(&current));
+        assert_eq!(payload["push_events"], true);
+        assert_eq!(payload["merge_requests_events"], true);
+        assert_eq!(payload["note_events"], false);
+        assert_eq!(payload["encryption_type"], 1);
+        assert_eq!(payload["password"], "s3cret");
+
+        let created = hook_payload("https://x", &HookOptions::default().with_events(&[HookEvent::TagPush]), None);
+        assert_eq!(created["push_events"], false);
+        assert_eq!(created["tag_push_events"], true);
+        assert!(created.get("encryption_type").is_none());
+    }
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use crate::utils::{deserialize_optional_string_or_int, deserialize_string_or_int};
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Hook {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub url: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_string_or_int")]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub result: Option<String>, // Response body of the last delivery
+    #[serde(default)]
+    pub result_code: Option<i32>, // HTTP status of the last delivery
+    #[serde(default)]
+    pub push_events: bool,
+    #[serde(default)]
+    pub tag_push_events: bool,
+    #[serde(default)]
+    pub issues_events: bool,
+    #[serde(default)]
+    pub note_events: bool,
+    #[serde(default)]
+    pub merge_requests_events: bool,
+}
+
+impl Hook {
+    /// The events this hook is subscribed to
+    pub fn events(&self) -> Vec<HookEvent> {
+        [
+            (self.push_events, HookEvent::Push),
+            (self.tag_push_events, HookEvent::TagPush),
+            (self.issues_events, HookEvent::Issues),
+            (self.note_events, HookEvent::Note),
+            (self.merge_requests_events, HookEvent::MergeRequests),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, event)| enabled.then_some(event))
+        .collect()
+    }
+}
+
+/// Events a repository webhook can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    Push,
+    TagPush,
+    Issues,
+    Note, // Comments on commits, issues and pull requests
+    MergeRequests,
+}
+
+impl HookEvent {
+    /// Parse `push`, `tag_push`, `issues`, `note` or `merge_requests`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "push" => Some(HookEvent::Push),
+            "tag_push" => Some(HookEvent::TagPush),
+            "issues" => Some(HookEvent::Issues),
+            "note" => Some(HookEvent::Note),
+            "merge_requests" => Some(HookEvent::MergeRequests),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HookEvent::Push => "push",
+            HookEvent::TagPush => "tag_push",
+            HookEvent::Issues => "issues",
+            HookEvent::Note => "note",
+            HookEvent::MergeRequests => "merge_requests",
+        }
+    }
+}
+
+/// How Gitee proves a delivery came from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookAuth {
+    /// Sent as-is in the `X-Gitee-Token` header
+    Password(String),
+    /// Used to sign each delivery; the signature is sent in `X-Gitee-Token`
+    SignKey(String),
+}
+
+/// Optional webhook settings; unset fields keep their current value on update
+#[derive(Debug, Clone, Default)]
+pub struct HookOptions {
+    pub auth: Option<HookAuth>,
+    pub push_events: Option<bool>, // Gitee enables push events when creating a hook unless told otherwise
+    pub tag_push_events: Option<bool>,
+    pub issues_events: Option<bool>,
+    pub note_events: Option<bool>,
+    pub merge_requests_events: Option<bool>,
+}
+
+impl HookOptions {
+    /// Subscribe to exactly the given events, turning every other event off
+    pub fn with_events(mut self, events: &[HookEvent]) -> Self {
+        self.push_events = Some(events.contains(&HookEvent::Push));
+        self.tag_push_events = Some(events.contains(&HookEvent::TagPush));
+        self.issues_events = Some(events.contains(&HookEvent::Issues));
+        self.note_events = Some(events.contains(&HookEvent::Note));
+        self.merge_requests_events = Some(events.contains(&HookEvent::MergeRequests));
+        self
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient, Repository};
+use reqwest::Method;
+use std::collections::HashMap;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List users who starred a repository
+    pub async fn list_stargazers(&self, owner: &str, repo: &str) -> Result<Vec<Stargazer>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/stargazers", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let stargazers: Vec<Stargazer> = response.json().await?;
+        Ok(stargazers)
+    }
+
+    /// Paginate over users who starred a repository
+    pub fn paginate_stargazers(&self, owner: &str, repo: &str) -> Paginator<'_, Stargazer> {
+        let url = format!("{}/repos/{}/{}/stargazers", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// List users who watch a repository
+    pub async fn list_watchers(&self, owner: &str, repo: &str) -> Result<Vec<Watcher>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/subscribers", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let watchers: Vec<Watcher> = response.json().await?;
+        Ok(watchers)
+    }
+
+    /// Paginate over users who watch a repository
+    pub fn paginate_watchers(&self, owner: &str, repo: &str) -> Paginator<'_, Watcher> {
+        let url = format!("{}/repos/{}/{}/subscribers", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// List forks of a repository
+    pub async fn list_forks(&self, owner: &str, repo: &str, options: Option<ForkListOptions>) -> Result<Vec<Repository>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/forks", self.base_url(), owner, repo);
+        let request = self
+            .request(Method::GET, &url)
+            .query(&options.unwrap_or_default());
+        let response = self.send(request).await?;
+
+        let forks: Vec<Repository> = response.json().await?;
+        Ok(forks)
+    }
+
+    /// Paginate over forks of a repository
+    pub fn paginate_forks(&self, owner: &str, repo: &str, options: Option<ForkListOptions>) -> Paginator<'_, Repository> {
+        let url = format!("{}/repos/{}/{}/forks", self.base_url(), owner, repo);
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List contributors with their commit counts, most active first
+    pub async fn list_contributors(&self, owner: &str, repo: &str, contributor_type: Option<ContributorType>) -> Result<Vec<Contributor>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/contributors", self.base_url(), owner, repo);
+        let contributor_type = contributor_type.unwrap_or_default();
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[("type", contributor_type.as_str())]);
+        let response = self.send(request).await?;
+
+        let mut contributors: Vec<Contributor> = response.json().await?;
+        contributors.sort_by_key(|c| std::cmp::Reverse(c.contributions));
+        Ok(contributors)
+    }
+
+    /// Get the language breakdown of a repository, largest share first
+    pub async fn list_languages(&self, owner: &str, repo: &str) -> Result<Vec<LanguageShare>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/languages", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let languages: HashMap<String, serde_json::Value> = response.json().await?;
+        Ok(language_shares(languages))
+    }
+}
+
+/// Turn Gitee's `{language: amount}` map into shares that add up to 100%.
+/// Amounts may be byte counts or percentages, as numbers or strings like `"61.2%"`.
+fn language_shares(languages: HashMap<String, serde_json::Value>) -> Vec<LanguageShare> {
+    let amounts: Vec<(String, f64)> = languages
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let amount = match value {
+                serde_json::Value::Number(n) => n.as_f64(),
+                serde_json::Value::String(s) => s.trim().trim_end_matches('%').parse().ok(),
+                _ => None,
+            }?;
+            Some((name, amount))
+        })
+        .collect();
+
+    let total: f64 = amounts.iter().map(|(_, amount)| amount).sum();
+    let mut shares: Vec<LanguageShare> = amounts
+        .into_iter()
+        .map(|(name, amount)| {
+            let percent = if total > 0.0 { amount * 100.0 / total } else { 0.0 };
+            LanguageShare { name, amount, percent }
+        })
+        .collect();
+    shares.sort_by(|a, b| b.amount.total_cmp(&a.amount).then_with(|| a.name.cmp(&b.name)));
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_shares_normalizes_amounts() {
+        let languages: HashMap<String, serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "Rust": 7500,
+            "Shell": "2500",
+            "Unknown": null
+        }))
+        .unwrap();
+        let shares = language_shares(languages);
+        assert_eq!(shares.len(), 2);
+        assert_eq!(shares[0].name, "Rust");
+        assert_eq!(shares[1].name, "Shell");
+        assert_eq!(shares[0].percent, 75.0);
+        assert_eq!(shares[1].percent, 25.0);
+    }
+
+    #[test]
+    fn test_stargazer_keeps_user_fields() {
+        let stargazer: Stargazer = serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "login": "octocat",
+            "avatar_url": "https://gitee.com/assets/no_portrait.png",
+            "html_url": "https://gitee.com/octocat",
+            "star_at": "2026-01-01T08:00:00+08:00"
+        }))
+        .unwrap();
+        assert_eq!(stargazer.user.id, "42");
+        assert_eq!(stargazer.user.login, "octocat");
+        assert_eq!(stargazer.star_at.as_deref(), Some("2026-01-01T08:00:00+08:00"));
+    }
+}
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use crate::users::User;
+
+/// A user who starred a repository
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stargazer {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(default)]
+    pub star_at: Option<String>,
+}
+
+/// A user who watches a repository
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Watcher {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(default)]
+    pub watch_at: Option<String>,
+}
+
+/// Order in which forks are listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForkSort {
+    /// Most recently created first
+    #[default]
+    Newest,
+    /// Oldest first
+    Oldest,
+    /// Most starred first
+    Stargazers,
+}
+
+impl ForkSort {
+    /// Parse `newest`, `oldest` or `stargazers`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "newest" => Some(ForkSort::Newest),
+            "oldest" => Some(ForkSort::Oldest),
+            "stargazers" => Some(ForkSort::Stargazers),
+            _ => None,
+        }
+    }
+
+    /// The name Gitee uses for this order
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ForkSort::Newest => "newest",
+            ForkSort::Oldest => "oldest",
+            ForkSort::Stargazers => "stargazers",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ForkListOptions {
+    pub sort: Option<ForkSort>,
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+/// Which side of a commit counts as a contribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContributorType {
+    /// Whoever committed the change
+    #[default]
+    Committers,
+    /// Whoever authored the change
+    Authors,
+}
+
+impl ContributorType {
+    /// Parse `committers` or `authors`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "committers" => Some(ContributorType::Committers),
+            "authors" => Some(ContributorType::Authors),
+            _ => None,
+        }
+    }
+
+    /// The name Gitee uses for this type
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContributorType::Committers => "committers",
+            ContributorType::Authors => "authors",
+        }
+    }
+}
+
+/// A contributor identified by the git name and email on their commits
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Contributor {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub contributions: i32, // Number of commits
+}
+
+/// Share of a repository's code written in one language
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LanguageShare {
+    pub name: String,
+    pub amount: f64, // Raw value reported by Gitee
+    pub percent: f64,
+}
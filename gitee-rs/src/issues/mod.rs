@@ -1,4 +1,4 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient};
 use reqwest::Method;
 
 mod models;
@@ -8,49 +8,45 @@ impl GiteeClient {
     /// List all issues
     pub async fn list_issues(&self, options: Option<IssueListOptions>) -> Result<Vec<Issue>, GiteeError> {
         let url = format!("{}/issues", self.base_url());
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(opts) = options {
             request = request.query(&opts);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list issues: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let issues: Vec<Issue> = response.json().await?;
         Ok(issues)
     }
 
+    /// Paginate over all issues
+    pub fn paginate_issues(&self, options: Option<IssueListOptions>) -> Paginator<'_, Issue> {
+        let url = format!("{}/issues", self.base_url());
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
     /// List repository issues
     pub async fn list_repo_issues(&self, owner: &str, repo: &str, options: Option<IssueListOptions>) -> Result<Vec<Issue>, GiteeError> {
         let url = format!("{}/repos/{}/{}/issues", self.base_url(), owner, repo);
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(opts) = options {
             request = request.query(&opts);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list repo issues: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let issues: Vec<Issue> = response.json().await?;
         Ok(issues)
     }
 
+    /// Paginate over repository issues
+    pub fn paginate_repo_issues(&self, owner: &str, repo: &str, options: Option<IssueListOptions>) -> Paginator<'_, Issue> {
+        let url = format!("{}/repos/{}/{}/issues", self.base_url(), owner, repo);
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
     /// Create a new issue
     pub async fn create_issue(
         &self,
@@ -68,20 +64,10 @@ impl GiteeClient {
             payload.insert("body", body.to_string());
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create issue: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let issue: Issue = response.json().await?;
         Ok(issue)
@@ -106,20 +92,10 @@ impl GiteeClient {
             "state": "closed"
         });
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to close issue: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let issue: Issue = response.json().await?;
         Ok(issue)
@@ -140,20 +116,10 @@ impl GiteeClient {
             payload.insert("state", s);  // "open" or "closed"
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to update issue: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let issue: Issue = response.json().await?;
         Ok(issue)
@@ -163,19 +129,8 @@ impl GiteeClient {
     pub async fn get_issue_detail(&self, owner: &str, repo: &str, number: &str) -> Result<Issue, GiteeError> {
         let url = format!("{}/repos/{}/{}/issues/{}", self.base_url(), owner, repo, number);
 
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get issue detail: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let issue: Issue = response.json().await?;
         Ok(issue)
@@ -187,20 +142,10 @@ impl GiteeClient {
 
         let payload = [("body", body)];
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .form(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to comment on issue: {}",
-                response.status()
-            )));
-        }
+            .form(&payload);
+        let response = self.send(request).await?;
 
         let comment: Comment = response.json().await?;
         Ok(comment)
@@ -210,47 +155,44 @@ impl GiteeClient {
     pub async fn list_issue_comments(&self, owner: &str, repo: &str, number: &str) -> Result<Vec<Comment>, GiteeError> {
         let url = format!("{}/repos/{}/{}/issues/{}/comments", self.base_url(), owner, repo, number);
 
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list issue comments: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let comments: Vec<Comment> = response.json().await?;
         Ok(comments)
     }
 
+    /// Paginate over issue comments
+    pub fn paginate_issue_comments(&self, owner: &str, repo: &str, number: &str) -> Paginator<'_, Comment> {
+        let url = format!("{}/repos/{}/{}/issues/{}/comments", self.base_url(), owner, repo, number);
+        self.paginate(url)
+    }
+
     /// List repository milestones
     pub async fn list_repo_milestones(&self, owner: &str, repo: &str, state: Option<&str>) -> Result<Vec<Milestone>, GiteeError> {
         let url = format!("{}/repos/{}/{}/milestones", self.base_url(), owner, repo);
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(s) = state {
             request = request.query(&[("state", s)]);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list milestones: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let milestones: Vec<Milestone> = response.json().await?;
         Ok(milestones)
     }
 
+    /// Paginate over repository milestones
+    pub fn paginate_repo_milestones(&self, owner: &str, repo: &str, state: Option<&str>) -> Paginator<'_, Milestone> {
+        let url = format!("{}/repos/{}/{}/milestones", self.base_url(), owner, repo);
+        let paginator = self.paginate(url);
+        match state {
+            Some(s) => paginator.param("state", s),
+            None => paginator,
+        }
+    }
+
     /// Create a new milestone
     pub async fn create_milestone(&self, owner: &str, repo: &str, title: &str, description: Option<&str>, due_on: Option<&str>) -> Result<Milestone, GiteeError> {
         let url = format!("{}/repos/{}/{}/milestones", self.base_url(), owner, repo);
@@ -266,20 +208,10 @@ impl GiteeClient {
             payload["due_on"] = serde_json::Value::String(due.to_string());
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create milestone: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let milestone: Milestone = response.json().await?;
         Ok(milestone)
@@ -288,19 +220,8 @@ impl GiteeClient {
     /// Get a milestone by number
     pub async fn get_milestone(&self, owner: &str, repo: &str, number: i32) -> Result<Milestone, GiteeError> {
         let url = format!("{}/repos/{}/{}/milestones/{}", self.base_url(), owner, repo, number);
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get milestone: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let milestone: Milestone = response.json().await?;
         Ok(milestone)
@@ -315,20 +236,10 @@ impl GiteeClient {
         if let Some(d) = description { payload.insert("description", d); }
         if let Some(s) = state { payload.insert("state", s); }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to update milestone: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let milestone: Milestone = response.json().await?;
         Ok(milestone)
@@ -337,20 +248,8 @@ impl GiteeClient {
     /// Delete a milestone
     pub async fn delete_milestone(&self, owner: &str, repo: &str, number: i32) -> Result<(), GiteeError> {
         let url = format!("{}/repos/{}/{}/milestones/{}", self.base_url(), owner, repo, number);
-        let response = self
-            .client()
-            .request(Method::DELETE, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to delete milestone: {}",
-                response.status()
-            )));
-        }
-
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
         Ok(())
     }
 }
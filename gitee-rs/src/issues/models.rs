@@ -11,7 +11,7 @@ pub struct Issue {
     pub number: String,
     pub title: String,
     pub body: Option<String>,
-    pub state: String, // "open" or "closed"
+    pub state: String, // "open", "progressing", "closed" or "rejected"
     pub html_url: String,
     #[serde(default)]
     pub api_url: String,
@@ -25,6 +25,24 @@ pub struct Issue {
     pub labels: Vec<Label>,
     #[serde(default)]
     pub milestone: Option<Milestone>,
+    #[serde(default)]
+    pub issue_type: Option<String>, // e.g. "任务", "缺陷" or "需求"
+    #[serde(default)]
+    pub issue_state: Option<String>, // Workflow state name, which enterprises can customise
+    #[serde(default)]
+    pub repository: Option<IssueRepository>, // Set on enterprise-wide listings
+}
+
+/// The repository an issue belongs to
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueRepository {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub full_name: String,
+    #[serde(default)]
+    pub human_name: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -0,0 +1,158 @@
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List deploy keys enabled on a repository
+    pub async fn list_deploy_keys(&self, owner: &str, repo: &str) -> Result<Vec<DeployKey>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let keys: Vec<DeployKey> = response.json().await?;
+        Ok(keys)
+    }
+
+    /// Paginate over deploy keys enabled on a repository
+    pub fn paginate_deploy_keys(&self, owner: &str, repo: &str) -> Paginator<'_, DeployKey> {
+        let url = format!("{}/repos/{}/{}/keys", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// List deploy keys from your other repositories that can be enabled on this one
+    pub async fn list_available_deploy_keys(&self, owner: &str, repo: &str) -> Result<Vec<DeployKey>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys/available", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let keys: Vec<DeployKey> = response.json().await?;
+        Ok(keys)
+    }
+
+    /// Get a deploy key
+    pub async fn get_deploy_key(&self, owner: &str, repo: &str, key_id: &str) -> Result<DeployKey, GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys/{}", self.base_url(), owner, repo, key_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let key: DeployKey = response.json().await?;
+        Ok(key)
+    }
+
+    /// Add a deploy key; `read_only: false` lets the key push
+    pub async fn add_deploy_key(&self, owner: &str, repo: &str, title: &str, key: &str, read_only: bool) -> Result<DeployKey, GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys", self.base_url(), owner, repo);
+
+        let payload = serde_json::json!({
+            "title": title,
+            "key": key,
+            "read_only": read_only,
+        });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let key: DeployKey = response.json().await?;
+        Ok(key)
+    }
+
+    /// Enable an existing deploy key on a repository
+    pub async fn enable_deploy_key(&self, owner: &str, repo: &str, key_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys/enable/{}", self.base_url(), owner, repo, key_id);
+        let request = self.request(Method::PUT, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Disable a deploy key on a repository without deleting it
+    pub async fn disable_deploy_key(&self, owner: &str, repo: &str, key_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys/enable/{}", self.base_url(), owner, repo, key_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Delete a deploy key
+    pub async fn delete_deploy_key(&self, owner: &str, repo: &str, key_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/keys/{}", self.base_url(), owner, repo, key_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// List SSH public keys of the authenticated user
+    pub async fn list_ssh_keys(&self) -> Result<Vec<SshKey>, GiteeError> {
+        let url = format!("{}/user/keys", self.base_url());
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let keys: Vec<SshKey> = response.json().await?;
+        Ok(keys)
+    }
+
+    /// Paginate over SSH public keys of the authenticated user
+    pub fn paginate_ssh_keys(&self) -> Paginator<'_, SshKey> {
+        let url = format!("{}/user/keys", self.base_url());
+        self.paginate(url)
+    }
+
+    /// Get an SSH public key of the authenticated user
+    pub async fn get_ssh_key(&self, key_id: &str) -> Result<SshKey, GiteeError> {
+        let url = format!("{}/user/keys/{}", self.base_url(), key_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let key: SshKey = response.json().await?;
+        Ok(key)
+    }
+
+    /// Add an SSH public key to the authenticated user
+    pub async fn add_ssh_key(&self, title: &str, key: &str) -> Result<SshKey, GiteeError> {
+        let url = format!("{}/user/keys", self.base_url());
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("title", title);
+        payload.insert("key", key);
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let key: SshKey = response.json().await?;
+        Ok(key)
+    }
+
+    /// Delete an SSH public key of the authenticated user
+    pub async fn delete_ssh_key(&self, key_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/user/keys/{}", self.base_url(), key_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deploy_key_without_read_only_flag() {
+        let key: DeployKey = serde_json::from_value(serde_json::json!({
+            "id": 301,
+            "key": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIExample ci@runner",
+            "url": "https://gitee.com/api/v5/repos/owner/repo/keys/301",
+            "title": "ci-runner",
+            "created_at": "2026-01-01T00:00:00+08:00"
+        }))
+        .unwrap();
+
+        assert_eq!(key.id, "301");
+        assert_eq!(key.title, "ci-runner");
+        assert_eq!(key.read_only, None);
+    }
+}
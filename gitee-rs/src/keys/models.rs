@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use crate::utils::deserialize_string_or_int;
+
+/// An SSH key granting access to a single repository
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DeployKey {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub title: String,
+    pub key: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub read_only: Option<bool>, // Not returned by every Gitee deployment
+}
+
+/// An SSH public key of the authenticated user
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SshKey {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub title: String,
+    pub key: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
@@ -1,4 +1,4 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient};
 use reqwest::Method;
 
 mod models;
@@ -8,24 +8,19 @@ impl GiteeClient {
     /// List all labels in a repository
     pub async fn list_labels(&self, owner: &str, repo: &str) -> Result<Vec<Label>, GiteeError> {
         let url = format!("{}/repos/{}/{}/labels", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list labels: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let labels: Vec<Label> = response.json().await?;
         Ok(labels)
     }
 
+    /// Paginate over all labels in a repository
+    pub fn paginate_labels(&self, owner: &str, repo: &str) -> Paginator<'_, Label> {
+        let url = format!("{}/repos/{}/{}/labels", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
     /// Create a new label in a repository
     pub async fn create_label(
         &self,
@@ -44,20 +39,10 @@ impl GiteeClient {
             payload.insert("description", desc.to_string());
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create label: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let label: Label = response.json().await?;
         Ok(label)
@@ -86,20 +71,10 @@ impl GiteeClient {
             payload.insert("description", desc.to_string());
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to update label: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let label: Label = response.json().await?;
         Ok(label)
@@ -113,20 +88,8 @@ impl GiteeClient {
         name: &str,
     ) -> Result<(), GiteeError> {
         let url = format!("{}/repos/{}/{}/labels/{}", self.base_url(), owner, repo, name);
-        let response = self
-            .client()
-            .request(Method::DELETE, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to delete label: {}",
-                response.status()
-            )));
-        }
-
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
         Ok(())
     }
 }
@@ -11,43 +11,104 @@ pub mod files;
 pub mod notifications;
 pub mod releases;
 pub mod wikis;
+pub mod branches;
+pub mod commits;
+pub mod tags;
+pub mod collaborators;
+pub mod orgs;
+pub mod enterprises;
+pub mod hooks;
+pub mod keys;
+pub mod webhooks;
+pub mod insights;
+pub mod pagination;
+pub mod retry;
+pub mod builder;
 pub(crate) mod utils;
 
-use reqwest::Client;
-use std::env;
+use reqwest::{Client, Method, RequestBuilder, Response};
+use std::sync::Mutex;
 
-pub use crate::error::GiteeError;
+pub use crate::error::{GiteeError, HttpError};
 pub use crate::users::{User, SearchUserResult};
-pub use crate::repos::Repository;
-pub use crate::issues::{Issue, Milestone, Comment as IssueComment};
-pub use crate::pulls::{PullRequest, BranchRef, FileDiff, Comment as PullComment};
+pub use crate::repos::{ArchiveFormat, Repository};
+pub use crate::issues::{Issue, IssueRepository, Milestone, Comment as IssueComment};
+pub use crate::pulls::{PullRequest, BranchRef, FileDiff, Comment as PullComment, PullReviewStatus, Reviewer};
 pub use crate::labels::Label;
-pub use crate::files::{FileContent, RepoFile};
+pub use crate::files::{Blob, FileContent, Readme, RepoFile, Tree, TreeEntry};
 pub use crate::notifications::{Notification};
-pub use crate::releases::{Release};
+pub use crate::releases::{Asset, Release};
 pub use crate::wikis::{WikiPage};
+pub use crate::branches::{Branch, ProtectionRule};
+pub use crate::commits::{Commit, CommitStats, Compare};
+pub use crate::tags::Tag;
+pub use crate::collaborators::{Collaborator, Permission};
+pub use crate::orgs::{OrgMember, OrgMembership, OrgRole, Organization};
+pub use crate::enterprises::{Enterprise, EnterpriseIssueState, EnterpriseMember};
+pub use crate::hooks::{Hook, HookEvent};
+pub use crate::keys::{DeployKey, SshKey};
+pub use crate::insights::{Contributor, LanguageShare, Stargazer, Watcher};
+pub use crate::webhooks::{WebhookEvent, WebhookReceiver, WebhookVerifier};
+pub use crate::pagination::{Page, Paginator};
+pub use crate::retry::{RateLimit, RetryPolicy};
+pub use crate::builder::GiteeClientBuilder;
 
 /// Gitee API Client
 pub struct GiteeClient {
     client: Client,
     base_url: String,
     token: String,
+    retry_policy: RetryPolicy,
+    rate_limit: Mutex<Option<RateLimit>>,
 }
 
 impl GiteeClient {
-    /// Creates a new GiteeClient instance
+    /// Creates a new GiteeClient instance with default HTTP settings
     pub fn new(token: Option<String>, base_url: Option<String>) -> Result<Self, error::GiteeError> {
-        let token = token.or_else(|| env::var("GITEE_TOKEN").ok())
-            .ok_or(error::GiteeError::TokenNotFound)?;
+        let mut builder = Self::builder();
+        if let Some(token) = token {
+            builder = builder.token(token);
+        }
+        if let Some(base_url) = base_url {
+            builder = builder.base_url(base_url);
+        }
+        builder.build()
+    }
 
-        let base_url = base_url.or_else(|| env::var("GITEE_API_BASE").ok())
-            .unwrap_or_else(|| "https://gitee.com/api/v5".to_string());
+    /// Creates a builder for configuring timeouts, proxy, certificates and more
+    pub fn builder() -> GiteeClientBuilder {
+        GiteeClientBuilder::new()
+    }
 
-        Ok(GiteeClient {
-            client: Client::new(),
+    pub(crate) fn from_parts(client: Client, base_url: String, token: String, retry_policy: RetryPolicy) -> Self {
+        GiteeClient {
+            client,
             base_url,
             token,
-        })
+            retry_policy,
+            rate_limit: Mutex::new(None),
+        }
+    }
+
+    /// Replace the retry policy used for every request
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the retry policy used for every request
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
+    /// Get the retry policy
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Rate-limit state reported by the most recent response, if any
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     /// Get the HTTP client
@@ -69,6 +130,61 @@ impl GiteeClient {
     pub fn auth_header(&self) -> String {
         format!("token {}", self.token)
     }
+
+    /// Start an authenticated request
+    pub(crate) fn request(&self, method: Method, url: &str) -> RequestBuilder {
+        self.client
+            .request(method, url)
+            .header("Authorization", self.auth_header())
+    }
+
+    /// Send a request, retrying transient failures according to the retry policy.
+    /// Non-success responses are turned into [`GiteeError::Http`].
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response, error::GiteeError> {
+        let request = request.build()?;
+        let method = request.method().clone();
+        let policy = &self.retry_policy;
+        let retryable = policy.allows_method(request.method());
+        let mut attempt = 0;
+
+        while retryable && attempt < policy.max_retries {
+            // Requests with streaming bodies cannot be replayed
+            let Some(current) = request.try_clone() else { break };
+
+            let delay = match self.client.execute(current).await {
+                Ok(response) => {
+                    self.record_rate_limit(&response);
+                    if !policy.should_retry(response.status(), response.headers()) {
+                        return Self::check_status(&method, response).await;
+                    }
+                    policy.delay_for(attempt, Some(response.headers()))
+                }
+                Err(err) if err.is_timeout() || err.is_connect() => policy.delay_for(attempt, None),
+                Err(err) => return Err(err.into()),
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+
+        let response = self.client.execute(request).await?;
+        self.record_rate_limit(&response);
+        Self::check_status(&method, response).await
+    }
+
+    async fn check_status(method: &Method, response: Response) -> Result<Response, error::GiteeError> {
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(HttpError::from_response(method, response).await.into())
+        }
+    }
+
+    fn record_rate_limit(&self, response: &Response) {
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+            *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(rate_limit);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -84,6 +200,23 @@ mod tests {
         assert_eq!(client.base_url(), "https://gitee.com/api/v5");
     }
 
+    #[test]
+    fn test_builder_configuration() {
+        let client = GiteeClient::builder()
+            .token("builder_token")
+            .base_url("https://gitee.example.com/api/v5/")
+            .timeout(std::time::Duration::from_secs(10))
+            .connect_timeout(std::time::Duration::from_secs(3))
+            .proxy("http://127.0.0.1:3128")
+            .user_agent("my-bot/1.0")
+            .retry_policy(RetryPolicy::disabled())
+            .build()
+            .unwrap();
+        assert_eq!(client.token(), "builder_token");
+        assert_eq!(client.base_url(), "https://gitee.example.com/api/v5");
+        assert_eq!(client.retry_policy().max_retries, 0);
+    }
+
     #[test]
     fn test_data_structures_deserialization() {
         let user_json = json!({
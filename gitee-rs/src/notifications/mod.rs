@@ -1,4 +1,4 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{error::GiteeError, pagination::Paginator, utils::extract_list, GiteeClient};
 use reqwest::Method;
 use serde_json::Value;
 
@@ -9,35 +9,20 @@ impl GiteeClient {
     /// List user notifications
     pub async fn list_user_notifications(&self) -> Result<Vec<Notification>, GiteeError> {
         let url = format!("{}/notifications/threads", self.base_url());
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list notifications: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let body = response.text().await?;
         let v: Value = serde_json::from_str(&body)?;
         
         // Gitee may return a wrapper object or a direct array
-        if let Some(items) = v.get("list") {
-            let list: Vec<Notification> = serde_json::from_value(items.clone())?;
-            Ok(list)
-        } else if let Some(items) = v.get("items") {
-            let list: Vec<Notification> = serde_json::from_value(items.clone())?;
-            Ok(list)
-        } else if v.is_array() {
-            let list: Vec<Notification> = serde_json::from_value(v)?;
-            Ok(list)
-        } else {
-            Ok(vec![])
-        }
+        let list: Vec<Notification> = extract_list(v)?;
+        Ok(list)
+    }
+
+    /// Paginate over user notifications
+    pub fn paginate_user_notifications(&self) -> Paginator<'_, Notification> {
+        let url = format!("{}/notifications/threads", self.base_url());
+        self.paginate(url)
     }
 }
@@ -0,0 +1,165 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient, Repository, User};
+use reqwest::Method;
+use std::collections::HashSet;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List organizations of the authenticated user
+    pub async fn list_user_orgs(&self, options: Option<OrgListOptions>) -> Result<Vec<Organization>, GiteeError> {
+        let url = format!("{}/user/orgs", self.base_url());
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let orgs: Vec<Organization> = response.json().await?;
+        Ok(orgs)
+    }
+
+    /// Paginate over organizations of the authenticated user
+    pub fn paginate_user_orgs(&self, options: Option<OrgListOptions>) -> Paginator<'_, Organization> {
+        let url = format!("{}/user/orgs", self.base_url());
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// Get an organization
+    pub async fn get_org(&self, org: &str) -> Result<Organization, GiteeError> {
+        let url = format!("{}/orgs/{}", self.base_url(), encode_path_segment(org));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let org: Organization = response.json().await?;
+        Ok(org)
+    }
+
+    /// List organization members, optionally filtered by role
+    pub async fn list_org_members(&self, org: &str, options: Option<OrgMemberListOptions>) -> Result<Vec<User>, GiteeError> {
+        let url = format!("{}/orgs/{}/members", self.base_url(), encode_path_segment(org));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let members: Vec<User> = response.json().await?;
+        Ok(members)
+    }
+
+    /// Paginate over organization members
+    pub fn paginate_org_members(&self, org: &str, options: Option<OrgMemberListOptions>) -> Paginator<'_, User> {
+        let url = format!("{}/orgs/{}/members", self.base_url(), encode_path_segment(org));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
+    /// List every organization member with their role.
+    /// The members endpoint does not report roles, so admins are fetched separately.
+    pub async fn list_org_member_roles(&self, org: &str) -> Result<Vec<OrgMember>, GiteeError> {
+        let admin_filter = OrgMemberListOptions { role: Some("admin".to_string()), ..Default::default() };
+        let admins: HashSet<String> = self
+            .paginate_org_members(org, Some(admin_filter))
+            .collect_all(None)
+            .await?
+            .into_iter()
+            .map(|user| user.login)
+            .collect();
+
+        let members = self.paginate_org_members(org, None).collect_all(None).await?;
+        Ok(members
+            .into_iter()
+            .map(|user| {
+                let role = if admins.contains(&user.login) { OrgRole::Admin } else { OrgRole::Member };
+                OrgMember { user, role }
+            })
+            .collect())
+    }
+
+    /// Get a user's membership in an organization
+    pub async fn get_org_membership(&self, org: &str, username: &str) -> Result<OrgMembership, GiteeError> {
+        let url = format!("{}/orgs/{}/memberships/{}", self.base_url(), encode_path_segment(org), encode_path_segment(username));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let membership: OrgMembership = response.json().await?;
+        Ok(membership)
+    }
+
+    /// Invite a user to an organization, or change the role of an existing member
+    pub async fn invite_org_member(&self, org: &str, username: &str, role: OrgRole) -> Result<OrgMembership, GiteeError> {
+        let url = format!("{}/orgs/{}/memberships/{}", self.base_url(), encode_path_segment(org), encode_path_segment(username));
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("role", role.as_str());
+
+        let request = self
+            .request(Method::PUT, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let membership: OrgMembership = response.json().await?;
+        Ok(membership)
+    }
+
+    /// Remove a member from an organization
+    pub async fn remove_org_member(&self, org: &str, username: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/orgs/{}/memberships/{}", self.base_url(), encode_path_segment(org), encode_path_segment(username));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// List repositories of an organization
+    pub async fn list_org_repos(&self, org: &str, options: Option<OrgRepoListOptions>) -> Result<Vec<Repository>, GiteeError> {
+        let url = format!("{}/orgs/{}/repos", self.base_url(), encode_path_segment(org));
+        let mut request = self.request(Method::GET, &url);
+
+        if let Some(opts) = options {
+            request = request.query(&opts);
+        }
+
+        let response = self.send(request).await?;
+
+        let repos: Vec<Repository> = response.json().await?;
+        Ok(repos)
+    }
+
+    /// Paginate over repositories of an organization
+    pub fn paginate_org_repos(&self, org: &str, options: Option<OrgRepoListOptions>) -> Paginator<'_, Repository> {
+        let url = format!("{}/orgs/{}/repos", self.base_url(), encode_path_segment(org));
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_role_and_repo_type_query() {
+        let membership: OrgMembership = serde_json::from_value(serde_json::json!({
+            "url": "https://gitee.com/api/v5/orgs/acme/memberships/alice",
+            "active": true,
+            "remark": null,
+            "role": "admin",
+            "organization_url": "https://gitee.com/api/v5/orgs/acme",
+            "organization": { "id": 7, "login": "acme", "name": "Acme", "avatar_url": "" },
+            "user": { "id": 1, "login": "alice", "avatar_url": "", "html_url": "https://gitee.com/alice" }
+        }))
+        .unwrap();
+
+        assert!(membership.is_admin());
+        assert_eq!(membership.organization.as_ref().map(|o| o.id.as_str()), Some("7"));
+
+        let options = OrgRepoListOptions { repo_type: Some("private".to_string()), ..Default::default() };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap()["type"],
+            serde_json::json!("private")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use crate::users::User;
+use crate::utils::deserialize_string_or_int;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Organization {
+    #[serde(deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub login: String, // Path used in URLs, e.g. `/orgs/{login}`
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub avatar_url: String,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub public: Option<bool>,
+    #[serde(default)]
+    pub members: Option<i32>, // Member count, only returned by the single-org endpoint
+    #[serde(default)]
+    pub public_repos: Option<i32>,
+    #[serde(default)]
+    pub private_repos: Option<i32>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Role of a user within an organization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrgRole {
+    Admin,
+    Member,
+}
+
+impl OrgRole {
+    /// Parse `admin` or `member`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "admin" => Some(OrgRole::Admin),
+            "member" => Some(OrgRole::Member),
+            _ => None,
+        }
+    }
+
+    /// The name Gitee uses for this role
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OrgRole::Admin => "admin",
+            OrgRole::Member => "member",
+        }
+    }
+}
+
+/// A user's membership in an organization
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrgMembership {
+    #[serde(default)]
+    pub active: bool, // False while an invitation is still pending
+    #[serde(default)]
+    pub remark: Option<String>,
+    pub role: String,
+    #[serde(default)]
+    pub organization: Option<Organization>,
+    #[serde(default)]
+    pub user: Option<User>,
+}
+
+impl OrgMembership {
+    /// Whether the member administers the organization
+    pub fn is_admin(&self) -> bool {
+        OrgRole::parse(&self.role) == Some(OrgRole::Admin)
+    }
+}
+
+/// An organization member together with their role
+#[derive(Debug, Clone, Serialize)]
+pub struct OrgMember {
+    pub user: User,
+    pub role: OrgRole,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct OrgListOptions {
+    pub admin: Option<bool>, // Only organizations the user administers
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct OrgMemberListOptions {
+    pub role: Option<String>, // "all", "admin" or "member"
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct OrgRepoListOptions {
+    #[serde(rename = "type")]
+    pub repo_type: Option<String>, // "all", "public" or "private"
+    pub page: Option<i32>,
+    pub per_page: Option<i32>,
+}
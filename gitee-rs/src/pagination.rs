@@ -0,0 +1,215 @@
+//! Automatic pagination for Gitee list endpoints
+//!
+//! Gitee reports paging information through the `total_count` and `total_page`
+//! response headers. [`Paginator`] follows them page by page and exposes the
+//! items as an async [`Stream`].
+
+use crate::{error::GiteeError, utils::extract_list, GiteeClient};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::marker::PhantomData;
+
+/// Default page size used when none is configured (Gitee allows up to 100)
+pub const DEFAULT_PER_PAGE: u32 = 100;
+
+/// A single page of results
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub page: u32,
+    pub total_count: Option<u64>,
+    pub total_page: Option<u32>,
+}
+
+impl<T> Page<T> {
+    /// Number of the page following this one, if any
+    pub fn next_page(&self, per_page: u32) -> Option<u32> {
+        if self.items.is_empty() {
+            return None;
+        }
+        match self.total_page {
+            Some(total) => (self.page < total).then_some(self.page + 1),
+            None => (self.items.len() as u32 >= per_page).then_some(self.page + 1),
+        }
+    }
+}
+
+/// Lazily walks every page of a list endpoint
+pub struct Paginator<'a, T> {
+    client: &'a GiteeClient,
+    url: String,
+    query: Vec<(String, String)>,
+    per_page: u32,
+    start_page: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: DeserializeOwned> Paginator<'a, T> {
+    pub(crate) fn new(client: &'a GiteeClient, url: String) -> Self {
+        Paginator {
+            client,
+            url,
+            query: Vec::new(),
+            per_page: DEFAULT_PER_PAGE,
+            start_page: 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Add the fields of a list options struct as query parameters.
+    /// `page` and `per_page` are taken over by the paginator.
+    pub fn options<Q: Serialize>(mut self, options: &Q) -> Self {
+        if let Ok(serde_json::Value::Object(map)) = serde_json::to_value(options) {
+            for (key, value) in map {
+                let value = match value {
+                    serde_json::Value::Null => continue,
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                match key.as_str() {
+                    "page" => self.start_page = value.parse().unwrap_or(self.start_page),
+                    "per_page" => self.per_page = value.parse().unwrap_or(self.per_page),
+                    _ => self.query.push((key, value)),
+                }
+            }
+        }
+        self
+    }
+
+    /// Add a single query parameter
+    pub fn param(mut self, key: &str, value: impl ToString) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Set the page size requested from the server
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+
+    /// Set the first page to fetch (1-based)
+    pub fn start_page(mut self, page: u32) -> Self {
+        self.start_page = page.max(1);
+        self
+    }
+
+    /// Fetch a single page
+    pub async fn fetch_page(&self, page: u32) -> Result<Page<T>, GiteeError> {
+        let request = self
+            .client
+            .request(Method::GET, &self.url)
+            .query(&self.query)
+            .query(&[("page", page), ("per_page", self.per_page)]);
+        let response = self.client.send(request).await?;
+
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let mut total_count = header("total_count");
+        let total_page = header("total_page").map(|v| v as u32);
+
+        let body: serde_json::Value = response.json().await?;
+        if total_count.is_none() {
+            total_count = body.get("total_count").and_then(|v| v.as_u64());
+        }
+        let items = extract_list(body)?;
+
+        Ok(Page {
+            items,
+            page,
+            total_count,
+            total_page,
+        })
+    }
+}
+
+impl<'a, T: DeserializeOwned + 'a> Paginator<'a, T> {
+    /// Stream every item across all pages
+    pub fn into_stream(self) -> impl Stream<Item = Result<T, GiteeError>> + 'a {
+        let first = self.start_page;
+        stream::try_unfold((self, Some(first)), |(paginator, next)| async move {
+            let Some(page) = next else {
+                return Ok::<_, GiteeError>(None);
+            };
+            let page = paginator.fetch_page(page).await?;
+            let next = page.next_page(paginator.per_page);
+            Ok(Some((page.items, (paginator, next))))
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Collect items from all pages, stopping once `limit` items have been gathered
+    pub async fn collect_all(self, limit: Option<usize>) -> Result<Vec<T>, GiteeError> {
+        let mut items = Vec::new();
+        if limit == Some(0) {
+            return Ok(items);
+        }
+
+        let mut stream = std::pin::pin!(self.into_stream());
+        while let Some(item) = stream.next().await {
+            items.push(item?);
+            if limit.is_some_and(|max| items.len() >= max) {
+                break;
+            }
+        }
+        Ok(items)
+    }
+}
+
+impl GiteeClient {
+    /// Paginate an arbitrary list endpoint, given its full URL
+    pub fn paginate<T: DeserializeOwned>(&self, url: impl Into<String>) -> Paginator<'_, T> {
+        Paginator::new(self, url.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(len: usize, page: u32, total_page: Option<u32>) -> Page<u32> {
+        Page {
+            items: vec![0; len],
+            page,
+            total_count: None,
+            total_page,
+        }
+    }
+
+    #[test]
+    fn test_next_page_follows_total_page_header() {
+        assert_eq!(page(100, 1, Some(3)).next_page(100), Some(2));
+        assert_eq!(page(20, 3, Some(3)).next_page(100), None);
+        assert_eq!(page(0, 1, Some(3)).next_page(100), None);
+    }
+
+    #[test]
+    fn test_next_page_without_headers_uses_page_size() {
+        assert_eq!(page(100, 1, None).next_page(100), Some(2));
+        assert_eq!(page(42, 2, None).next_page(100), None);
+    }
+
+    #[test]
+    fn test_options_are_flattened_into_query() {
+        let client = GiteeClient::new(Some("fake_token".to_string()), None).unwrap();
+        let options = crate::issues::IssueListOptions {
+            state: Some("open".to_string()),
+            page: Some(3),
+            per_page: Some(20),
+            ..Default::default()
+        };
+
+        let paginator: Paginator<'_, crate::Issue> = client.paginate("https://example.com").options(&options);
+        assert_eq!(paginator.query, vec![("state".to_string(), "open".to_string())]);
+        assert_eq!(paginator.start_page, 3);
+        assert_eq!(paginator.per_page, 20);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{commits::Commit, error::GiteeError, pagination::Paginator, GiteeClient};
 use reqwest::Method;
 
 mod models;
@@ -8,26 +8,24 @@ impl GiteeClient {
     /// List all pull requests for a repository
     pub async fn list_pulls(&self, owner: &str, repo: &str, options: Option<PullListOptions>) -> Result<Vec<PullRequest>, GiteeError> {
         let url = format!("{}/repos/{}/{}/pulls", self.base_url(), owner, repo);
-        let mut request = self.client().request(Method::GET, &url)
-            .header("Authorization", self.auth_header());
+        let mut request = self.request(Method::GET, &url);
         
         if let Some(opts) = options {
             request = request.query(&opts);
         }
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list pull requests: {}",
-                response.status()
-            )));
-        }
+        let response = self.send(request).await?;
 
         let pulls: Vec<PullRequest> = response.json().await?;
         Ok(pulls)
     }
 
+    /// Paginate over all pull requests for a repository
+    pub fn paginate_pulls(&self, owner: &str, repo: &str, options: Option<PullListOptions>) -> Paginator<'_, PullRequest> {
+        let url = format!("{}/repos/{}/{}/pulls", self.base_url(), owner, repo);
+        self.paginate(url).options(&options.unwrap_or_default())
+    }
+
     /// Create a new pull request
     pub async fn create_pull(
         &self,
@@ -48,20 +46,10 @@ impl GiteeClient {
             payload.insert("body", body.to_string());
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create pull request: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let pull: PullRequest = response.json().await?;
         Ok(pull)
@@ -86,20 +74,10 @@ impl GiteeClient {
             "state": "closed"
         });
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to close pull request: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let pull: PullRequest = response.json().await?;
         Ok(pull)
@@ -111,6 +89,7 @@ impl GiteeClient {
         owner: &str,
         repo: &str,
         pull_number: &str,
+        options: Option<MergeOptions>,
     ) -> Result<PullRequest, GiteeError> {
         let url = format!(
             "{}/repos/{}/{}/pulls/{}/merge",
@@ -120,19 +99,10 @@ impl GiteeClient {
             pull_number
         );
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PUT, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to merge pull request: {}",
-                response.status()
-            )));
-        }
+            .json(&options.unwrap_or_default());
+        self.send(request).await?;
 
         // Gitee API returns different response for merge, so we'll return the PR info
         // by getting the PR again after merging
@@ -143,19 +113,8 @@ impl GiteeClient {
     pub async fn get_pull_detail(&self, owner: &str, repo: &str, number: &str) -> Result<PullRequest, GiteeError> {
         let url = format!("{}/repos/{}/{}/pulls/{}", self.base_url(), owner, repo, number);
 
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get pull request detail: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let pull: PullRequest = response.json().await?;
         Ok(pull)
@@ -176,20 +135,10 @@ impl GiteeClient {
             payload.insert("state", s);  // "open" or "closed"
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::PATCH, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to update pull request: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let pull: PullRequest = response.json().await?;
         Ok(pull)
@@ -201,20 +150,10 @@ impl GiteeClient {
 
         let payload = [("body", body)];
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .form(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to comment on pull request: {}",
-                response.status()
-            )));
-        }
+            .form(&payload);
+        let response = self.send(request).await?;
 
         let comment: Comment = response.json().await?;
         Ok(comment)
@@ -224,43 +163,479 @@ impl GiteeClient {
     pub async fn list_pull_comments(&self, owner: &str, repo: &str, number: &str) -> Result<Vec<Comment>, GiteeError> {
         let url = format!("{}/repos/{}/{}/pulls/{}/comments", self.base_url(), owner, repo, number);
 
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list pull request comments: {}",
-                response.status()
-            )));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let comments: Vec<Comment> = response.json().await?;
+        Ok(comments)
+    }
+
+    /// Paginate over pull request comments
+    pub fn paginate_pull_comments(&self, owner: &str, repo: &str, number: &str) -> Paginator<'_, Comment> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/comments", self.base_url(), owner, repo, number);
+        self.paginate(url)
+    }
+
+    /// List the commits of a pull request
+    pub async fn list_pull_commits(&self, owner: &str, repo: &str, number: &str) -> Result<Vec<Commit>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/commits", self.base_url(), owner, repo, number);
+
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let commits: Vec<Commit> = response.json().await?;
+        Ok(commits)
+    }
+
+    /// Get the unified diff of a pull request as text.
+    /// Built from the per-file patches of the API, so it works for private repositories too;
+    /// files Gitee returns without a patch (binary or too large) are listed as `Binary files ... differ`.
+    pub async fn get_pull_diff(&self, owner: &str, repo: &str, number: &str) -> Result<String, GiteeError> {
+        let files = self.get_diff_files(owner, repo, number).await?;
+        Ok(render_diff(&files))
+    }
+
+    /// Get the pull request as a series of `git format-patch` style patches, one per commit
+    pub async fn get_pull_patch(&self, owner: &str, repo: &str, number: &str) -> Result<String, GiteeError> {
+        let commits = self.list_pull_commits(owner, repo, number).await?;
+        let total = commits.len();
+
+        let mut patch = String::new();
+        for (index, commit) in commits.iter().enumerate() {
+            // The commit list omits file changes, so each commit is fetched on its own
+            let commit = self.get_commit(owner, repo, &commit.sha).await?;
+            patch.push_str(&render_commit_patch(&commit, index + 1, total));
         }
+        Ok(patch)
+    }
+
+    /// Create a review comment anchored to a line of the diff
+    pub async fn create_pull_review_comment(&self, owner: &str, repo: &str, number: &str, body: &str, anchor: &DiffPosition) -> Result<Comment, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/comments", self.base_url(), owner, repo, number);
+
+        let mut payload = serde_json::to_value(anchor)?;
+        payload["body"] = body.into();
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let comment: Comment = response.json().await?;
+        Ok(comment)
+    }
+
+    /// List the line-level review comments of a pull request
+    pub async fn list_pull_review_comments(&self, owner: &str, repo: &str, number: &str) -> Result<Vec<Comment>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/comments", self.base_url(), owner, repo, number);
+
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[("comment_type", "diff_comment")]);
+        let response = self.send(request).await?;
 
         let comments: Vec<Comment> = response.json().await?;
         Ok(comments)
     }
 
+    /// Reply to a review comment, keeping the reply on the same line of the diff
+    pub async fn reply_pull_review_comment(&self, owner: &str, repo: &str, number: &str, comment_id: &str, body: &str) -> Result<Comment, GiteeError> {
+        let parent = self.get_pull_comment(owner, repo, comment_id).await?;
+        let url = format!("{}/repos/{}/{}/pulls/{}/comments", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({
+            "body": body,
+            "commit_id": parent.commit_id,
+            "path": parent.path,
+            "position": parent.position,
+            "in_reply_to": comment_id,
+        });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let comment: Comment = response.json().await?;
+        Ok(comment)
+    }
+
+    /// Get a pull request comment by id
+    pub async fn get_pull_comment(&self, owner: &str, repo: &str, comment_id: &str) -> Result<Comment, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/comments/{}", self.base_url(), owner, repo, comment_id);
+
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let comment: Comment = response.json().await?;
+        Ok(comment)
+    }
+
+    /// Edit a pull request comment
+    pub async fn update_pull_comment(&self, owner: &str, repo: &str, comment_id: &str, body: &str) -> Result<Comment, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/comments/{}", self.base_url(), owner, repo, comment_id);
+
+        let payload = serde_json::json!({ "body": body });
+
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let comment: Comment = response.json().await?;
+        Ok(comment)
+    }
+
+    /// Delete a pull request comment
+    pub async fn delete_pull_comment(&self, owner: &str, repo: &str, comment_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/comments/{}", self.base_url(), owner, repo, comment_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
     /// Get diff files for a pull request
     pub async fn get_diff_files(&self, owner: &str, repo: &str, number: &str) -> Result<Vec<FileDiff>, GiteeError> {
         let url = format!("{}/repos/{}/{}/pulls/{}/files", self.base_url(), owner, repo, number);
 
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get diff files: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let files: Vec<FileDiff> = response.json().await?;
         Ok(files)
     }
+
+    /// Assign reviewers (审查人员) to a pull request
+    pub async fn add_pull_reviewers(&self, owner: &str, repo: &str, number: &str, reviewers: &[&str]) -> Result<PullRequest, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/assignees", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "assignees": reviewers.join(",") });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let pull: PullRequest = response.json().await?;
+        Ok(pull)
+    }
+
+    /// Remove reviewers from a pull request
+    pub async fn remove_pull_reviewers(&self, owner: &str, repo: &str, number: &str, reviewers: &[&str]) -> Result<PullRequest, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/assignees", self.base_url(), owner, repo, number);
+
+        let request = self
+            .request(Method::DELETE, &url)
+            .query(&[("assignees", reviewers.join(","))]);
+        let response = self.send(request).await?;
+
+        let pull: PullRequest = response.json().await?;
+        Ok(pull)
+    }
+
+    /// Assign testers (测试人员) to a pull request
+    pub async fn add_pull_testers(&self, owner: &str, repo: &str, number: &str, testers: &[&str]) -> Result<PullRequest, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/testers", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "testers": testers.join(",") });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let pull: PullRequest = response.json().await?;
+        Ok(pull)
+    }
+
+    /// Remove testers from a pull request
+    pub async fn remove_pull_testers(&self, owner: &str, repo: &str, number: &str, testers: &[&str]) -> Result<PullRequest, GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/testers", self.base_url(), owner, repo, number);
+
+        let request = self
+            .request(Method::DELETE, &url)
+            .query(&[("testers", testers.join(","))]);
+        let response = self.send(request).await?;
+
+        let pull: PullRequest = response.json().await?;
+        Ok(pull)
+    }
+
+    /// Approve the review of a pull request (审查通过).
+    /// With `force`, a repository admin can pass the review for all reviewers.
+    pub async fn approve_pull_review(&self, owner: &str, repo: &str, number: &str, force: bool) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/review", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "force": force });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Mark the test of a pull request as passed (测试通过).
+    /// With `force`, a repository admin can pass the test for all testers.
+    pub async fn pass_pull_test(&self, owner: &str, repo: &str, number: &str, force: bool) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/test", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "force": force });
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Reset the review state; `reset_all` also resets approvals of reviewers other than the caller
+    pub async fn reset_pull_review(&self, owner: &str, repo: &str, number: &str, reset_all: bool) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/assignees", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "reset_all": reset_all });
+
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Reset the test state; `reset_all` also resets passes of testers other than the caller
+    pub async fn reset_pull_test(&self, owner: &str, repo: &str, number: &str, reset_all: bool) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/pulls/{}/testers", self.base_url(), owner, repo, number);
+
+        let payload = serde_json::json!({ "reset_all": reset_all });
+
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// Get the review and test progress of a pull request
+    pub async fn get_pull_review_status(&self, owner: &str, repo: &str, number: &str) -> Result<PullReviewStatus, GiteeError> {
+        let pull = self.get_pull_detail(owner, repo, number).await?;
+        Ok(PullReviewStatus::from_pull(&pull))
+    }
+}
+
+fn render_diff(files: &[FileDiff]) -> String {
+    let mut diff = String::new();
+    for file in files {
+        let name = &file.filename;
+        diff.push_str(&format!("diff --git a/{} b/{}\n", name, name));
+        let old = if file.status == "added" { "/dev/null".to_string() } else { format!("a/{}", name) };
+        let new = if matches!(file.status.as_str(), "removed" | "deleted") { "/dev/null".to_string() } else { format!("b/{}", name) };
+        match file.patch.as_deref() {
+            Some(patch) => {
+                diff.push_str(&format!("--- {}\n+++ {}\n", old, new));
+                diff.push_str(patch);
+                if !patch.ends_with('\n') {
+                    diff.push('\n');
+                }
+            }
+            None => diff.push_str(&format!("Binary files {} and {} differ\n", old, new)),
+        }
+    }
+    diff
+}
+
+fn render_commit_patch(commit: &Commit, index: usize, total: usize) -> String {
+    let author = commit.commit.author.as_ref();
+    let message = commit.commit.message.as_deref().unwrap_or_default();
+    let body = message.split_once('\n').map(|(_, rest)| rest.trim()).unwrap_or_default();
+
+    let mut patch = format!("From {} Mon Sep 17 00:00:00 2001\n", commit.sha);
+    patch.push_str(&format!(
+        "From: {} <{}>\n",
+        author.and_then(|a| a.name.as_deref()).unwrap_or_default(),
+        author.and_then(|a| a.email.as_deref()).unwrap_or_default()
+    ));
+    if let Some(date) = author.and_then(|a| a.date.as_deref()) {
+        patch.push_str(&format!("Date: {}\n", date));
+    }
+    patch.push_str(&format!("Subject: [PATCH {}/{}] {}\n\n", index, total, commit.summary()));
+    if !body.is_empty() {
+        patch.push_str(body);
+        patch.push('\n');
+    }
+    patch.push_str("---\n");
+    patch.push_str(&render_diff(&commit.files));
+    patch.push('\n');
+    patch
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn branch_ref(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "label": name,
+            "ref": name,
+            "sha": "abc",
+            "user": { "id": 1, "login": "owner", "avatar_url": "", "html_url": "" }
+        })
+    }
+
+    #[test]
+    fn test_review_status_counts_accepted_reviewers() {
+        let pull: PullRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "number": 7,
+            "title": "Add feature",
+            "body": null,
+            "state": "open",
+            "html_url": "https://gitee.com/owner/repo/pulls/7",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "updated_at": "2026-01-01T00:00:00+08:00",
+            "head": branch_ref("feature"),
+            "base": branch_ref("master"),
+            "assignees": [
+                { "id": 2, "login": "alice", "accept": true },
+                { "id": 3, "login": "bob", "accept": false }
+            ],
+            "testers": [{ "id": 4, "login": "carol", "accept": false }],
+            "assignees_number": 1,
+            "testers_number": 1
+        }))
+        .unwrap();
+
+        let status = PullReviewStatus::from_pull(&pull);
+        assert_eq!(status.approvals, 1);
+        assert!(status.review_passed);
+        assert_eq!(status.tests_passed, 0);
+        assert!(!status.test_passed);
+    }
+
+    #[test]
+    fn test_review_comment_carries_diff_anchor() {
+        let comment: Comment = serde_json::from_value(serde_json::json!({
+            "id": 101,
+            "body": "Consider a guard here",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "updated_at": "2026-01-01T00:00:00+08:00",
+            "comment_type": "diff_comment",
+            "path": "src/lib.rs",
+            "position": 12,
+            "commit_id": "abc",
+            "in_reply_to_id": 100
+        }))
+        .unwrap();
+
+        assert!(comment.is_review_comment());
+        assert_eq!(comment.path.as_deref(), Some("src/lib.rs"));
+        assert_eq!(comment.position, Some(12));
+        assert_eq!(comment.in_reply_to.as_deref(), Some("100"));
+    }
+
+    #[test]
+    fn test_pull_request_merge_details() {
+        let pull: PullRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "number": 8,
+            "title": "Fix bug",
+            "body": "details",
+            "state": "merged",
+            "html_url": "https://gitee.com/owner/repo/pulls/8",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "updated_at": "2026-01-02T00:00:00+08:00",
+            "merged_at": "2026-01-02T00:00:00+08:00",
+            "merge_commit_sha": "def",
+            "mergeable": true,
+            "draft": false,
+            "head": branch_ref("fix"),
+            "base": branch_ref("master"),
+            "labels": [{ "id": 5, "name": "bug", "color": "d73a4a" }],
+            "milestone": { "id": 9, "number": 1, "title": "v1.0", "state": "open" },
+            "diff_url": "https://gitee.com/owner/repo/pulls/8.diff"
+        }))
+        .unwrap();
+
+        assert!(pull.is_merged());
+        assert_eq!(pull.mergeable, Some(true));
+        assert_eq!(pull.labels[0].name, "bug");
+        assert_eq!(pull.milestone.as_ref().map(|m| m.title.as_str()), Some("v1.0"));
+        assert_eq!(pull.merge_commit_sha.as_deref(), Some("def"));
+    }
+
+    #[test]
+    fn test_merge_blockers_and_options() {
+        let pull: PullRequest = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "number": 9,
+            "title": "Conflicting change",
+            "body": null,
+            "state": "open",
+            "html_url": "https://gitee.com/owner/repo/pulls/9",
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "updated_at": "2026-01-01T00:00:00+08:00",
+            "head": branch_ref("topic"),
+            "base": branch_ref("master"),
+            "mergeable": false,
+            "assignees": [{ "id": 2, "login": "alice", "accept": false }],
+            "assignees_number": 1
+        }))
+        .unwrap();
+
+        let blockers = pull.merge_blockers();
+        assert_eq!(blockers.len(), 2);
+        assert!(blockers[0].contains("conflicts"));
+        assert!(blockers[1].contains("0 of 1"));
+
+        let options = MergeOptions {
+            merge_method: MergeMethod::parse("Squash"),
+            prune_source_branch: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({ "merge_method": "squash", "prune_source_branch": true })
+        );
+    }
+
+    fn file_diff(filename: &str, status: &str, patch: Option<&str>) -> FileDiff {
+        serde_json::from_value(serde_json::json!({
+            "filename": filename,
+            "status": status,
+            "additions": 1,
+            "deletions": 0,
+            "changes": 1,
+            "patch": patch
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diff_is_rendered_from_file_patches() {
+        let files = vec![
+            file_diff("src/lib.rs", "modified", Some("@@ -1 +1 @@\n-old\n+new")),
+            file_diff("NEW.md", "added", Some("@@ -0,0 +1 @@\n+hello\n")),
+            file_diff("logo.png", "removed", None),
+        ];
+        assert_eq!(
+            render_diff(&files),
+            "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1 +1 @@\n-old\n+new\n\
+             diff --git a/NEW.md b/NEW.md\n--- /dev/null\n+++ b/NEW.md\n@@ -0,0 +1 @@\n+hello\n\
+             diff --git a/logo.png b/logo.png\nBinary files a/logo.png and /dev/null differ\n"
+        );
+    }
+
+    #[test]
+    fn test_commit_patch_has_format_patch_headers() {
+        let commit: Commit = serde_json::from_value(serde_json::json!({
+            "sha": "abc123",
+            "commit": {
+                "message": "Fix parser\n\nHandle empty input.",
+                "author": { "name": "Dev", "email": "dev@example.com", "date": "2026-01-01T08:00:00+08:00" }
+            },
+            "files": [{ "filename": "a.rs", "status": "modified", "additions": 1, "deletions": 1, "changes": 2, "patch": "@@ -1 +1 @@\n-a\n+b" }]
+        }))
+        .unwrap();
+        let patch = render_commit_patch(&commit, 1, 2);
+        assert!(patch.starts_with("From abc123 Mon Sep 17 00:00:00 2001\nFrom: Dev <dev@example.com>\nDate: 2026-01-01T08:00:00+08:00\nSubject: [PATCH 1/2] Fix parser\n\nHandle empty input.\n---\ndiff --git a/a.rs b/a.rs\n"));
+    }
 }
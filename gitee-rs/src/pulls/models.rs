@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use crate::users::User;
 use crate::repos::Repository;
-use crate::utils::deserialize_string_or_int;
+use crate::labels::Label;
+use crate::issues::Milestone;
+use crate::utils::{deserialize_optional_string_or_int, deserialize_string_or_int};
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PullRequest {
@@ -21,6 +23,112 @@ pub struct PullRequest {
     pub assignee: Option<User>,
     pub head: BranchRef,
     pub base: BranchRef,
+    #[serde(default)]
+    pub assignees: Vec<Reviewer>, // Reviewers (审查人员)
+    #[serde(default)]
+    pub testers: Vec<Reviewer>,
+    #[serde(default)]
+    pub assignees_number: i32, // Approvals required before the review passes
+    #[serde(default)]
+    pub testers_number: i32,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub mergeable: Option<bool>, // None while Gitee is still checking for conflicts
+    #[serde(default)]
+    pub merged_at: Option<String>,
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    #[serde(default)]
+    pub merge_commit_sha: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub milestone: Option<Milestone>,
+    #[serde(default)]
+    pub diff_url: Option<String>,
+    #[serde(default)]
+    pub patch_url: Option<String>,
+}
+
+impl PullRequest {
+    /// Whether the pull request has been merged
+    pub fn is_merged(&self) -> bool {
+        self.state == "merged" || self.merged_at.is_some()
+    }
+
+    /// Reasons the pull request should not be merged yet; empty when it is ready
+    pub fn merge_blockers(&self) -> Vec<String> {
+        let mut blockers = Vec::new();
+        if self.state != "open" {
+            blockers.push(format!("pull request is {}", self.state));
+        }
+        if self.draft {
+            blockers.push("pull request is still a draft".to_string());
+        }
+        if self.mergeable == Some(false) {
+            blockers.push("pull request has conflicts with the base branch".to_string());
+        }
+        let status = PullReviewStatus::from_pull(self);
+        if !status.review_passed {
+            blockers.push(format!(
+                "{} of {} required review approvals",
+                status.approvals, status.required_approvals
+            ));
+        }
+        if !status.test_passed {
+            blockers.push(format!(
+                "{} of {} required test passes",
+                status.tests_passed, status.required_tests
+            ));
+        }
+        blockers
+    }
+}
+
+/// A reviewer or tester assigned to a pull request
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Reviewer {
+    #[serde(default, deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Gitee API may return string or integer IDs
+    pub login: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub accept: bool, // Whether this person has approved / passed the test
+}
+
+/// Review and test progress of a pull request
+#[derive(Debug, Clone, Serialize)]
+pub struct PullReviewStatus {
+    pub reviewers: Vec<Reviewer>,
+    pub testers: Vec<Reviewer>,
+    pub required_approvals: i32,
+    pub required_tests: i32,
+    pub approvals: i32,
+    pub tests_passed: i32,
+    pub review_passed: bool,
+    pub test_passed: bool,
+}
+
+impl PullReviewStatus {
+    /// Derive the status from the reviewer and tester lists of a pull request
+    pub fn from_pull(pull: &PullRequest) -> Self {
+        let approvals = pull.assignees.iter().filter(|r| r.accept).count() as i32;
+        let tests_passed = pull.testers.iter().filter(|r| r.accept).count() as i32;
+        PullReviewStatus {
+            reviewers: pull.assignees.clone(),
+            testers: pull.testers.clone(),
+            required_approvals: pull.assignees_number,
+            required_tests: pull.testers_number,
+            approvals,
+            tests_passed,
+            review_passed: approvals >= pull.assignees_number,
+            test_passed: tests_passed >= pull.testers_number,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -43,20 +151,93 @@ pub struct Comment {
     pub user: Option<User>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub comment_type: Option<String>, // "diff_comment" for line comments, "pr_comment" otherwise
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub position: Option<i32>,
+    #[serde(default)]
+    pub original_position: Option<i32>,
+    #[serde(default)]
+    pub commit_id: Option<String>,
+    #[serde(default)]
+    pub original_commit_id: Option<String>,
+    #[serde(default, alias = "in_reply_to_id", deserialize_with = "deserialize_optional_string_or_int")]
+    pub in_reply_to: Option<String>,
+}
+
+impl Comment {
+    /// Whether the comment is anchored to a line of the diff
+    pub fn is_review_comment(&self) -> bool {
+        self.comment_type.as_deref() == Some("diff_comment") || self.path.is_some()
+    }
+}
+
+/// Where a review comment is anchored in the diff
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffPosition {
+    /// Sha of the commit being commented on, usually the head of the pull request
+    pub commit_id: String,
+    /// File path relative to the repository root
+    pub path: String,
+    /// Line index within the file's diff, counting from the first `@@` hunk header
+    pub position: i32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FileDiff {
+    #[serde(default)]
     pub sha: String,
     pub filename: String,
     pub status: String, // "added", "removed", "modified"
     pub additions: i32,
     pub deletions: i32,
     pub changes: i32,
-    #[serde(rename = "blob_url")]
+    #[serde(rename = "blob_url", default)]
     pub blob_url: String,
-    #[serde(rename = "raw_url")]
+    #[serde(rename = "raw_url", default)]
     pub raw_url: String,
+    #[serde(default)]
+    pub patch: Option<String>, // Unified diff, omitted by Gitee for large or binary files
+}
+
+/// Strategy used to bring a pull request into its base branch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    /// Create a merge commit
+    #[default]
+    Merge,
+    /// Squash all commits into one
+    Squash,
+    /// Rebase the commits onto the base branch
+    Rebase,
+}
+
+impl MergeMethod {
+    /// Parse `merge`, `squash` or `rebase`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "merge" => Some(MergeMethod::Merge),
+            "squash" => Some(MergeMethod::Squash),
+            "rebase" => Some(MergeMethod::Rebase),
+            _ => None,
+        }
+    }
+}
+
+/// Optional settings for merging a pull request
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MergeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_method: Option<MergeMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>, // Merge commit title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>, // Merge commit description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prune_source_branch: Option<bool>, // Delete the source branch after merging
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize)]
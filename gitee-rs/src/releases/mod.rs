@@ -1,48 +1,43 @@
-use crate::{error::GiteeError, GiteeClient};
-use reqwest::Method;
-use serde::{Deserialize, Serialize};
-use crate::utils::deserialize_string_or_int;
-
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct Release {
-    #[serde(deserialize_with = "deserialize_string_or_int")]
-    pub id: String,
-    pub tag_name: String,
-    pub target_commitish: String,
-    pub name: String,
-    pub body: Option<String>,
-    pub draft: bool,
-    pub prerelease: bool,
-    pub created_at: String,
-    pub published_at: String,
-}
+use crate::{error::GiteeError, pagination::Paginator, utils::{encode_path_segment, write_body}, GiteeClient};
+use reqwest::{multipart, Method};
+use std::path::Path;
+use tokio::io::AsyncWrite;
+use tokio_util::io::ReaderStream;
+
+mod models;
+pub use models::*;
 
 impl GiteeClient {
-    /// Create a release
-    pub async fn create_release(&self, owner: &str, repo: &str, tag_name: &str, name: &str, body: Option<&str>) -> Result<Release, GiteeError> {
+    /// Create a release. Gitee requires a target, so the repository's default branch
+    /// is used when `target_commitish` is not set.
+    pub async fn create_release(&self, owner: &str, repo: &str, tag_name: &str, name: &str, body: Option<&str>, options: Option<ReleaseOptions>) -> Result<Release, GiteeError> {
         let url = format!("{}/repos/{}/{}/releases", self.base_url(), owner, repo);
+        let options = options.unwrap_or_default();
+
+        let target_commitish = match options.target_commitish {
+            Some(target) => target,
+            None => self
+                .get_repo(owner, repo)
+                .await?
+                .default_branch
+                .unwrap_or_else(|| "master".to_string()),
+        };
 
-        let mut payload = std::collections::HashMap::new();
-        payload.insert("tag_name", tag_name.to_string());
-        payload.insert("name", name.to_string());
-        if let Some(body) = body {
-            payload.insert("body", body.to_string());
+        let mut payload = serde_json::json!({
+            "tag_name": tag_name,
+            "name": name,
+            "body": body.unwrap_or_default(),
+            "target_commitish": target_commitish,
+            "prerelease": options.prerelease.unwrap_or(false),
+        });
+        if let Some(draft) = options.draft {
+            payload["draft"] = draft.into();
         }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create release: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let release: Release = response.json().await?;
         Ok(release)
@@ -51,21 +46,204 @@ impl GiteeClient {
     /// List releases
     pub async fn list_releases(&self, owner: &str, repo: &str) -> Result<Vec<Release>, GiteeError> {
         let url = format!("{}/repos/{}/{}/releases", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list releases: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let releases: Vec<Release> = response.json().await?;
         Ok(releases)
     }
-}
\ No newline at end of file
+
+    /// Paginate over all releases
+    pub fn paginate_releases(&self, owner: &str, repo: &str) -> Paginator<'_, Release> {
+        let url = format!("{}/repos/{}/{}/releases", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// Get a release by id
+    pub async fn get_release(&self, owner: &str, repo: &str, release_id: &str) -> Result<Release, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}", self.base_url(), owner, repo, release_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let release: Release = response.json().await?;
+        Ok(release)
+    }
+
+    /// Get the latest release
+    pub async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<Release, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/latest", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let release: Release = response.json().await?;
+        Ok(release)
+    }
+
+    /// Get the release for a tag
+    pub async fn get_release_by_tag(&self, owner: &str, repo: &str, tag: &str) -> Result<Release, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/tags/{}", self.base_url(), owner, repo, encode_path_segment(tag));
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let release: Release = response.json().await?;
+        Ok(release)
+    }
+
+    /// Update a release. Gitee expects the tag, name and body on every update,
+    /// so fields left unset are filled in from the current release.
+    pub async fn update_release(&self, owner: &str, repo: &str, release_id: &str, update: ReleaseUpdate) -> Result<Release, GiteeError> {
+        let current = self.get_release(owner, repo, release_id).await?;
+        let url = format!("{}/repos/{}/{}/releases/{}", self.base_url(), owner, repo, release_id);
+
+        let payload = serde_json::json!({
+            "tag_name": update.tag_name.unwrap_or(current.tag_name),
+            "name": update.name.unwrap_or(current.name),
+            "body": update.body.or(current.body).unwrap_or_default(),
+            "prerelease": update.prerelease.unwrap_or(current.prerelease),
+        });
+
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let release: Release = response.json().await?;
+        Ok(release)
+    }
+
+    /// Delete a release (the tag is kept)
+    pub async fn delete_release(&self, owner: &str, repo: &str, release_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}", self.base_url(), owner, repo, release_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+
+    /// List the files attached to a release
+    pub async fn list_release_assets(&self, owner: &str, repo: &str, release_id: &str) -> Result<Vec<Asset>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files", self.base_url(), owner, repo, release_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let assets: Vec<Asset> = response.json().await?;
+        Ok(assets)
+    }
+
+    /// Get a release attachment by id
+    pub async fn get_release_asset(&self, owner: &str, repo: &str, release_id: &str, asset_id: &str) -> Result<Asset, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files/{}", self.base_url(), owner, repo, release_id, asset_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let asset: Asset = response.json().await?;
+        Ok(asset)
+    }
+
+    /// Upload a local file as a release attachment, using its file name.
+    /// The file is streamed from disk rather than read into memory.
+    pub async fn upload_release_asset(&self, owner: &str, repo: &str, release_id: &str, file_path: impl AsRef<Path>) -> Result<Asset, GiteeError> {
+        let file_path = file_path.as_ref();
+        let path_error = |err: std::io::Error| {
+            GiteeError::IoError(std::io::Error::new(err.kind(), format!("{}: {}", file_path.display(), err)))
+        };
+        let name = file_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| path_error(std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a file name")))?
+            .to_string();
+        let file = tokio::fs::File::open(file_path).await.map_err(path_error)?;
+        let length = file.metadata().await.map_err(path_error)?.len();
+
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files", self.base_url(), owner, repo, release_id);
+
+        let body = reqwest::Body::wrap_stream(ReaderStream::new(file));
+        let part = multipart::Part::stream_with_length(body, length).file_name(name);
+        let form = multipart::Form::new().part("file", part);
+
+        let request = self
+            .request(Method::POST, &url)
+            .multipart(form);
+        let response = self.send(request).await?;
+
+        let asset: Asset = response.json().await?;
+        Ok(asset)
+    }
+
+    /// Upload in-memory data as a release attachment named `name`
+    pub async fn upload_release_asset_bytes(&self, owner: &str, repo: &str, release_id: &str, name: &str, data: Vec<u8>) -> Result<Asset, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files", self.base_url(), owner, repo, release_id);
+
+        let part = multipart::Part::bytes(data).file_name(name.to_string());
+        let form = multipart::Form::new().part("file", part);
+
+        let request = self
+            .request(Method::POST, &url)
+            .multipart(form);
+        let response = self.send(request).await?;
+
+        let asset: Asset = response.json().await?;
+        Ok(asset)
+    }
+
+    /// Stream a release attachment into `writer`, returning the number of bytes written
+    pub async fn download_release_asset<W: AsyncWrite + Unpin>(&self, owner: &str, repo: &str, release_id: &str, asset_id: &str, writer: &mut W) -> Result<u64, GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files/{}/download", self.base_url(), owner, repo, release_id, asset_id);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        write_body(response, writer).await
+    }
+
+    /// Delete a release attachment
+    pub async fn delete_release_asset(&self, owner: &str, repo: &str, release_id: &str, asset_id: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/releases/{}/attach_files/{}", self.base_url(), owner, repo, release_id, asset_id);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_with_source_archives_deserializes() {
+        let body = serde_json::json!({
+            "id": 42,
+            "tag_name": "v1.2.0",
+            "target_commitish": "master",
+            "prerelease": false,
+            "name": "v1.2.0",
+            "body": null,
+            "created_at": "2026-01-01T00:00:00+08:00",
+            "assets": [
+                { "id": 7, "name": "gitee-linux.tar.gz", "size": 1024, "browser_download_url": "https://gitee.com/o/r/releases/download/v1.2.0/gitee-linux.tar.gz" },
+                { "name": "v1.2.0.zip", "browser_download_url": "https://gitee.com/o/r/repository/archive/v1.2.0.zip" }
+            ]
+        });
+
+        let release: Release = serde_json::from_value(body).unwrap();
+        assert_eq!(release.id, "42");
+        assert!(!release.draft);
+        assert_eq!(release.assets[0].id, "7");
+        assert_eq!(release.assets[1].id, "");
+    }
+
+    #[tokio::test]
+    async fn test_upload_reports_the_offending_path() {
+        let client = GiteeClient::new(Some("fake_token".to_string()), None).unwrap();
+
+        let missing = client.upload_release_asset("owner", "repo", "1", "/nonexistent/dist/app.tar.gz").await;
+        match missing {
+            Err(GiteeError::IoError(err)) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+                assert!(err.to_string().contains("/nonexistent/dist/app.tar.gz"));
+            }
+            other => panic!("unexpected result {:?}", other.map(|a| a.id)),
+        }
+
+        let no_name = client.upload_release_asset("owner", "repo", "1", "/").await;
+        assert!(matches!(no_name, Err(GiteeError::IoError(err)) if err.kind() == std::io::ErrorKind::InvalidInput));
+    }
+}
@@ -10,27 +10,54 @@ pub struct Release {
     pub target_commitish: String,
     pub name: String,
     pub body: Option<String>,
+    #[serde(default)]
     pub draft: bool,
+    #[serde(default)]
     pub prerelease: bool,
     pub created_at: String,
+    #[serde(default)]
     pub published_at: Option<String>,
     #[serde(default)]
     pub author: Option<User>,
     #[serde(default)]
-    pub assets: Vec<Asset>,
+    pub assets: Vec<Asset>, // Uploaded attachments plus the generated source archives
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Asset {
-    #[serde(deserialize_with = "deserialize_string_or_int")]
-    pub id: String,  // Gitee API may return string or integer IDs
+    #[serde(default, deserialize_with = "deserialize_string_or_int")]
+    pub id: String,  // Empty for the generated source archives
     pub name: String,
     #[serde(default)]
     pub label: Option<String>,
-    pub content_type: String,
-    pub size: i32,
-    pub download_count: i32,
-    pub created_at: String,
-    pub updated_at: String,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    #[serde(default)]
+    pub size: Option<i64>,
+    #[serde(default)]
+    pub download_count: Option<i32>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub uploader: Option<User>,
     pub browser_download_url: String,
 }
+
+/// Optional settings for creating a release
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseOptions {
+    pub target_commitish: Option<String>, // Branch or commit to tag; defaults to the default branch
+    pub prerelease: Option<bool>,
+    pub draft: Option<bool>,
+}
+
+/// Fields to change on an existing release; unset fields keep their current value
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseUpdate {
+    pub tag_name: Option<String>,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub prerelease: Option<bool>,
+}
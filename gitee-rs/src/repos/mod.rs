@@ -1,8 +1,10 @@
-use crate::{error::GiteeError, GiteeClient};
+use crate::{error::GiteeError, pagination::Paginator, GiteeClient};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use crate::users::User;
-use crate::utils::deserialize_string_or_int;
+use crate::pulls::MergeMethod;
+use crate::utils::{deserialize_string_or_int, write_body};
+use tokio::io::AsyncWrite;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Repository {
@@ -40,25 +42,77 @@ pub struct Repository {
     pub license: Option<String>,
     #[serde(default)]
     pub pushed_at: Option<String>,
+    #[serde(default)]
+    pub path: Option<String>, // URL path, which may differ from the display name
+    #[serde(default)]
+    pub homepage: Option<String>,
+}
+
+/// Settings to change on a repository; unset fields keep their current value
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RepoUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_requests_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub squash_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rebase_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_merge_method: Option<MergeMethod>,
+}
+
+/// Archive format for repository snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// Guess the format from a file name, e.g. `out.tar.gz` or `out.zip`
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        if name.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else {
+            None
+        }
+    }
+
+    fn endpoint(self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zipball",
+            ArchiveFormat::TarGz => "tarball",
+        }
+    }
 }
 
 impl GiteeClient {
     /// Get repository information
     pub async fn get_repo(&self, owner: &str, repo: &str) -> Result<Repository, GiteeError> {
         let url = format!("{}/repos/{}/{}", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get repository: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let repo: Repository = response.json().await?;
         Ok(repo)
@@ -76,20 +130,10 @@ impl GiteeClient {
         }
         payload.insert("auto_init", "true");
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create repository: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let repo: Repository = response.json().await?;
         Ok(repo)
@@ -107,20 +151,10 @@ impl GiteeClient {
         }
         payload.insert("auto_init", "true");
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create organization repository: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let repo: Repository = response.json().await?;
         Ok(repo)
@@ -138,20 +172,10 @@ impl GiteeClient {
         }
         payload.insert("auto_init", "true");
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .json(&payload)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to create enterprise repository: {}",
-                response.status()
-            )));
-        }
+            .json(&payload);
+        let response = self.send(request).await?;
 
         let repo: Repository = response.json().await?;
         Ok(repo)
@@ -160,40 +184,24 @@ impl GiteeClient {
     /// List user repositories
     pub async fn list_user_repos(&self) -> Result<Vec<Repository>, GiteeError> {
         let url = format!("{}/user/repos", self.base_url());
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to list user repositories: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let repos: Vec<Repository> = response.json().await?;
         Ok(repos)
     }
 
+    /// Paginate over all user repositories
+    pub fn paginate_user_repos(&self) -> Paginator<'_, Repository> {
+        let url = format!("{}/user/repos", self.base_url());
+        self.paginate(url)
+    }
+
     /// Fork a repository
     pub async fn fork_repository(&self, owner: &str, repo: &str) -> Result<Repository, GiteeError> {
         let url = format!("{}/repos/{}/{}/forks", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::POST, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to fork repository: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::POST, &url);
+        let response = self.send(request).await?;
 
         let repo: Repository = response.json().await?;
         Ok(repo)
@@ -208,20 +216,10 @@ impl GiteeClient {
         if let Some(s) = size { params.push(("size", s.to_string())); }
         if let Some(st) = sort { params.push(("sort", st.to_string())); }
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .query(&params)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to search repositories: {}",
-                response.status()
-            )));
-        }
+            .query(&params);
+        let response = self.send(request).await?;
 
         let body = response.text().await?;
         let v: serde_json::Value = serde_json::from_str(&body)?;
@@ -237,64 +235,64 @@ impl GiteeClient {
         }
     }
 
-    /// Delete a repository
-    pub async fn delete_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
+    /// Update repository settings
+    pub async fn update_repo(&self, owner: &str, repo: &str, update: RepoUpdate) -> Result<Repository, GiteeError> {
         let url = format!("{}/repos/{}/{}", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::DELETE, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to delete repository: {}",
-                response.status()
-            )));
+
+        // Gitee requires the name on every update
+        let mut payload = serde_json::to_value(&update)?;
+        if update.name.is_none() {
+            payload["name"] = serde_json::json!(self.get_repo(owner, repo).await?.name);
         }
 
+        let request = self
+            .request(Method::PATCH, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let repo: Repository = response.json().await?;
+        Ok(repo)
+    }
+
+    /// Transfer a repository to another user or organization
+    pub async fn transfer_repo(&self, owner: &str, repo: &str, new_owner: &str) -> Result<Repository, GiteeError> {
+        let url = format!("{}/repos/{}/{}/transfer", self.base_url(), owner, repo);
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("new_owner", new_owner);
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let repo: Repository = response.json().await?;
+        Ok(repo)
+    }
+
+    /// Delete a repository
+    pub async fn delete_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}", self.base_url(), owner, repo);
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
         Ok(())
     }
 
     /// Star a repository
     pub async fn star_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
         let url = format!("{}/user/starred/{}/{}", self.base_url(), owner, repo);
-        let response = self
-            .client()
+        let request = self
             .request(Method::PUT, &url)
-            .header("Authorization", self.auth_header())
-            .header("Content-Length", 0)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to star repository: {}",
-                response.status()
-            )));
-        }
-
+            .header("Content-Length", 0);
+        self.send(request).await?;
         Ok(())
     }
 
     /// Unstar a repository
     pub async fn unstar_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
         let url = format!("{}/user/starred/{}/{}", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::DELETE, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to unstar repository: {}",
-                response.status()
-            )));
-        }
-
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
         Ok(())
     }
 
@@ -302,41 +300,61 @@ impl GiteeClient {
     pub async fn watch_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
         let url = format!("{}/user/subscriptions/{}/{}", self.base_url(), owner, repo);
         
-        let response = self
-            .client()
+        let request = self
             .request(Method::PUT, &url)
-            .header("Authorization", self.auth_header())
-            .header("Content-Length", 0)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to watch repository: {}",
-                response.status()
-            )));
-        }
-
+            .header("Content-Length", 0);
+        self.send(request).await?;
         Ok(())
     }
 
     /// Unwatch a repository
     pub async fn unwatch_repo(&self, owner: &str, repo: &str) -> Result<(), GiteeError> {
         let url = format!("{}/user/subscriptions/{}/{}", self.base_url(), owner, repo);
-        let response = self
-            .client()
-            .request(Method::DELETE, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to unwatch repository: {}",
-                response.status()
-            )));
-        }
-
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
         Ok(())
     }
+
+    /// Stream an archive of the repository at `ref` (branch, tag or commit) into `writer`,
+    /// returning the number of bytes written
+    pub async fn download_archive<W: AsyncWrite + Unpin>(&self, owner: &str, repo: &str, r#ref: &str, format: ArchiveFormat, writer: &mut W) -> Result<u64, GiteeError> {
+        let url = format!("{}/repos/{}/{}/{}", self.base_url(), owner, repo, format.endpoint());
+        let request = self
+            .request(Method::GET, &url)
+            .query(&[("ref", r#ref)]);
+        let response = self.send(request).await?;
+
+        write_body(response, writer).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_format_from_file_name() {
+        assert_eq!(ArchiveFormat::from_file_name("out.zip"), Some(ArchiveFormat::Zip));
+        assert_eq!(ArchiveFormat::from_file_name("dist/v1.2.TAR.GZ"), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::from_file_name("out.tgz"), Some(ArchiveFormat::TarGz));
+        assert_eq!(ArchiveFormat::from_file_name("out.tar"), None);
+    }
+
+    #[test]
+    fn test_repo_update_only_sends_changed_fields() {
+        let update = RepoUpdate {
+            description: Some("New description".to_string()),
+            private: Some(false),
+            default_merge_method: Some(MergeMethod::Squash),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            serde_json::json!({
+                "description": "New description",
+                "private": false,
+                "default_merge_method": "squash"
+            })
+        );
+    }
 }
@@ -0,0 +1,202 @@
+//! Retry policy and rate-limit tracking for the shared HTTP layer
+
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Controls how failed requests are retried
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound for any single delay, including server-provided ones
+    pub max_backoff: Duration,
+    /// Factor applied to the delay after each retry
+    pub multiplier: f64,
+    /// Randomize delays to avoid synchronized retries
+    pub jitter: bool,
+    /// Also retry POST and PATCH requests
+    pub retry_non_idempotent: bool,
+    /// HTTP status codes that trigger a retry
+    pub retry_statuses: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: true,
+            retry_non_idempotent: false,
+            retry_statuses: vec![429, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn disabled() -> Self {
+        RetryPolicy {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Whether requests with this method may be retried
+    pub fn allows_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent
+            || matches!(
+                *method,
+                Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+            )
+    }
+
+    /// Whether a response with this status (and headers) should be retried
+    pub fn should_retry(&self, status: StatusCode, headers: &HeaderMap) -> bool {
+        if self.retry_statuses.contains(&status.as_u16()) {
+            return true;
+        }
+        // Gitee answers 403 once the rate limit is used up
+        status == StatusCode::FORBIDDEN
+            && RateLimit::from_headers(headers).and_then(|r| r.remaining) == Some(0)
+    }
+
+    /// Exponential backoff delay for the given retry attempt (0-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let base = self.initial_backoff.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = base.min(self.max_backoff.as_secs_f64());
+        let delay = if self.jitter {
+            // Equal jitter: keep half of the delay, randomize the other half
+            capped / 2.0 + capped / 2.0 * random_fraction()
+        } else {
+            capped
+        };
+        Duration::from_secs_f64(delay)
+    }
+
+    /// Delay before the next attempt, preferring `Retry-After` and rate-limit
+    /// reset hints from the server over the computed backoff
+    pub(crate) fn delay_for(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        let hinted = headers.and_then(|h| {
+            retry_after(h).or_else(|| {
+                RateLimit::from_headers(h)
+                    .filter(|r| r.remaining == Some(0))
+                    .and_then(|r| r.reset_after())
+            })
+        });
+        match hinted {
+            Some(delay) => delay.min(self.max_backoff),
+            None => self.backoff(attempt),
+        }
+    }
+}
+
+/// Rate-limit state reported by the server on the last response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    /// Reset time as a Unix timestamp in seconds
+    pub reset: Option<u64>,
+}
+
+impl RateLimit {
+    /// Parse the `X-RateLimit-*` headers, if present
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let limit = header_u64(headers, "x-ratelimit-limit");
+        let remaining = header_u64(headers, "x-ratelimit-remaining");
+        let reset = header_u64(headers, "x-ratelimit-reset");
+        if limit.is_none() && remaining.is_none() && reset.is_none() {
+            return None;
+        }
+        Some(RateLimit { limit, remaining, reset })
+    }
+
+    /// Time left until the limit resets
+    pub fn reset_after(&self) -> Option<Duration> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        self.reset.map(|reset| Duration::from_secs(reset.saturating_sub(now)))
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    header_u64(headers, "retry-after").map(Duration::from_secs)
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            jitter: false,
+            max_backoff: Duration::from_secs(3),
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(5), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::default();
+        for _ in 0..100 {
+            let delay = policy.backoff(1);
+            assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_only_idempotent_methods_retry_by_default() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_method(&Method::GET));
+        assert!(policy.allows_method(&Method::DELETE));
+        assert!(!policy.allows_method(&Method::POST));
+        assert!(!policy.allows_method(&Method::PATCH));
+    }
+
+    #[test]
+    fn test_retry_after_header_is_honored() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("7"));
+        assert_eq!(policy.delay_for(0, Some(&headers)), Duration::from_secs(7));
+        assert!(policy.should_retry(StatusCode::TOO_MANY_REQUESTS, &headers));
+    }
+
+    #[test]
+    fn test_exhausted_rate_limit_on_forbidden_is_retried() {
+        let policy = RetryPolicy::default();
+        let mut headers = HeaderMap::new();
+        assert!(!policy.should_retry(StatusCode::FORBIDDEN, &headers));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        assert!(policy.should_retry(StatusCode::FORBIDDEN, &headers));
+    }
+}
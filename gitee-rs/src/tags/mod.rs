@@ -0,0 +1,75 @@
+use crate::{error::GiteeError, pagination::Paginator, utils::encode_path_segment, GiteeClient};
+use reqwest::Method;
+
+mod models;
+pub use models::*;
+
+impl GiteeClient {
+    /// List repository tags
+    pub async fn list_tags(&self, owner: &str, repo: &str) -> Result<Vec<Tag>, GiteeError> {
+        let url = format!("{}/repos/{}/{}/tags", self.base_url(), owner, repo);
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
+
+        let tags: Vec<Tag> = response.json().await?;
+        Ok(tags)
+    }
+
+    /// Paginate over repository tags
+    pub fn paginate_tags(&self, owner: &str, repo: &str) -> Paginator<'_, Tag> {
+        let url = format!("{}/repos/{}/{}/tags", self.base_url(), owner, repo);
+        self.paginate(url)
+    }
+
+    /// Create a tag on a branch or commit. A `message` makes it an annotated tag,
+    /// otherwise a lightweight tag is created.
+    pub async fn create_tag(&self, owner: &str, repo: &str, tag_name: &str, refs: &str, message: Option<&str>) -> Result<Tag, GiteeError> {
+        let url = format!("{}/repos/{}/{}/tags", self.base_url(), owner, repo);
+
+        let mut payload = std::collections::HashMap::new();
+        payload.insert("tag_name", tag_name);
+        payload.insert("refs", refs);
+        if let Some(message) = message {
+            payload.insert("tag_message", message);
+        }
+
+        let request = self
+            .request(Method::POST, &url)
+            .json(&payload);
+        let response = self.send(request).await?;
+
+        let tag: Tag = response.json().await?;
+        Ok(tag)
+    }
+
+    /// Delete a tag
+    pub async fn delete_tag(&self, owner: &str, repo: &str, tag_name: &str) -> Result<(), GiteeError> {
+        let url = format!("{}/repos/{}/{}/tags/{}", self.base_url(), owner, repo, encode_path_segment(tag_name));
+        let request = self.request(Method::DELETE, &url);
+        self.send(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(message: Option<&str>) -> Tag {
+        serde_json::from_value(serde_json::json!({
+            "name": "v1.2.0",
+            "message": message,
+            "commit": { "sha": "8d3c9b1f" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tag_is_annotated() {
+        assert!(tag(Some("Release 1.2.0")).is_annotated());
+        // Lightweight tags come back with no message, an empty one or just whitespace
+        assert!(!tag(None).is_annotated());
+        assert!(!tag(Some("")).is_annotated());
+        assert!(!tag(Some(" \n")).is_annotated());
+    }
+}
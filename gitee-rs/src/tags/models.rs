@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use crate::commits::GitActor;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tag {
+    pub name: String,
+    #[serde(default)]
+    pub message: Option<String>, // Empty for lightweight tags
+    pub commit: TagCommit,
+    #[serde(default)]
+    pub tagger: Option<GitActor>, // Only set for annotated tags
+}
+
+impl Tag {
+    /// Whether this is an annotated tag (carries a message)
+    pub fn is_annotated(&self) -> bool {
+        self.message.as_deref().is_some_and(|m| !m.trim().is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TagCommit {
+    pub sha: String,
+    #[serde(default)]
+    pub date: Option<String>,
+}
@@ -9,19 +9,8 @@ impl GiteeClient {
     /// Get authenticated user information (current user)
     pub async fn get_authenticated_user(&self) -> Result<User, GiteeError> {
         let url = format!("{}/user", self.base_url());
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get authenticated user info: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let user: User = response.json().await?;
         Ok(user)
@@ -30,19 +19,8 @@ impl GiteeClient {
     /// Get user information by username
     pub async fn get_user_info(&self, username: &str) -> Result<User, GiteeError> {
         let url = format!("{}/users/{}", self.base_url(), username);
-        let response = self
-            .client()
-            .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to get user info: {}",
-                response.status()
-            )));
-        }
+        let request = self.request(Method::GET, &url);
+        let response = self.send(request).await?;
 
         let user: User = response.json().await?;
         Ok(user)
@@ -53,20 +31,10 @@ impl GiteeClient {
         let url = format!("{}/search/users", self.base_url());
         let params = [("q", query), ("per_page", "30")];
 
-        let response = self
-            .client()
+        let request = self
             .request(Method::GET, &url)
-            .header("Authorization", self.auth_header())
-            .query(&params)
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Err(GiteeError::ApiError(format!(
-                "Failed to search users: {}",
-                response.status()
-            )));
-        }
+            .query(&params);
+        let response = self.send(request).await?;
 
         let body = response.text().await?;
         let v: Value = serde_json::from_str(&body)?;
@@ -1,5 +1,10 @@
-use serde::de::{self, Deserializer};
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
 use std::fmt;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use crate::error::GiteeError;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 
 // Helper function to deserialize either string or integer IDs as strings
 pub(crate) fn deserialize_string_or_int<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -57,3 +62,89 @@ where
 
     deserializer.deserialize_any(StringOrIntVisitor)
 }
+
+// Like `deserialize_string_or_int`, for optional IDs that may be missing or null
+pub(crate) fn deserialize_optional_string_or_int<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(other) => Err(de::Error::custom(format!("expected string or integer, got {}", other))),
+    }
+}
+
+// Gitee list endpoints return either a bare array or a wrapper object
+pub(crate) fn extract_list<T: DeserializeOwned>(value: Value) -> Result<Vec<T>, serde_json::Error> {
+    match value {
+        Value::Array(_) => serde_json::from_value(value),
+        Value::Object(mut map) => {
+            for key in ["list", "items", "data"] {
+                if let Some(items @ Value::Array(_)) = map.remove(key) {
+                    return serde_json::from_value(items);
+                }
+            }
+            Ok(Vec::new())
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+// Percent-encode a value used as a single URL path segment (branch names may contain '/')
+pub(crate) fn encode_path_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+// Percent-encode a repository file path, keeping '/' as the separator between segments
+pub(crate) fn encode_file_path(path: &str) -> String {
+    path.trim_matches('/')
+        .split('/')
+        .map(encode_path_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Copy a response body into `writer` chunk by chunk, returning the number of bytes written
+pub(crate) async fn write_body<W: AsyncWrite + Unpin>(mut response: reqwest::Response, writer: &mut W) -> Result<u64, GiteeError> {
+    let mut written = 0u64;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+// Decode a `content`/`encoding` pair as returned by the contents and blob endpoints.
+// `name` identifies the object in error messages.
+pub(crate) fn decode_content(content: Option<&str>, encoding: Option<&str>, name: &str) -> Result<Vec<u8>, GiteeError> {
+    let content = content.ok_or_else(|| {
+        GiteeError::DecodeError(format!("no content returned for '{}'", name))
+    })?;
+    match encoding {
+        Some("base64") => {
+            // Gitee wraps the base64 payload across lines
+            let compact: String = content.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+            STANDARD.decode(compact).map_err(|e| {
+                GiteeError::DecodeError(format!("invalid base64 content for '{}': {}", name, e))
+            })
+        }
+        None | Some("") | Some("utf-8") => Ok(content.as_bytes().to_vec()),
+        Some(other) => Err(GiteeError::DecodeError(format!("unsupported encoding '{}' for '{}'", other, name))),
+    }
+}
+
+pub(crate) fn decode_text(bytes: Vec<u8>, name: &str) -> Result<String, GiteeError> {
+    String::from_utf8(bytes).map_err(|e| {
+        GiteeError::DecodeError(format!("'{}' is not valid UTF-8 (binary file?): {}", name, e))
+    })
+}
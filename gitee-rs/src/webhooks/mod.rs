@@ -0,0 +1,239 @@
+//! Types and helpers for receiving Gitee webhook deliveries.
+//!
+//! Parse a delivery with [`WebhookEvent::parse`], check its `X-Gitee-Token` with
+//! [`WebhookVerifier`], or let a [`WebhookReceiver`] do both and dispatch events to
+//! registered handlers. With the `webhook-server` feature the receiver can be
+//! mounted as an axum router.
+
+use crate::{error::GiteeError, hooks::HookAuth};
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use percent_encoding::percent_decode_str;
+use serde_json::Value;
+use sha2::Sha256;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod models;
+mod receiver;
+pub use models::*;
+pub use receiver::*;
+
+impl WebhookEvent {
+    /// Parse a delivery body. The event kind comes from the `X-Gitee-Event` header when
+    /// given, otherwise from the payload's `hook_name`.
+    pub fn parse(event: Option<&str>, body: &[u8]) -> Result<Self, GiteeError> {
+        let payload: Value = serde_json::from_slice(body)?;
+        let name = match event {
+            Some(event) => event.to_string(),
+            None => payload.get("hook_name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+        };
+
+        let event = match WebhookEventKind::parse(&name) {
+            Some(WebhookEventKind::Push) => WebhookEvent::Push(Box::new(serde_json::from_value(payload)?)),
+            Some(WebhookEventKind::TagPush) => WebhookEvent::TagPush(Box::new(serde_json::from_value(payload)?)),
+            Some(WebhookEventKind::Issue) => WebhookEvent::Issue(Box::new(serde_json::from_value(payload)?)),
+            Some(WebhookEventKind::Note) => WebhookEvent::Note(Box::new(serde_json::from_value(payload)?)),
+            Some(WebhookEventKind::MergeRequest) => WebhookEvent::MergeRequest(Box::new(serde_json::from_value(payload)?)),
+            None => WebhookEvent::Unknown { event: name, payload },
+        };
+        Ok(event)
+    }
+}
+
+/// How far a signed delivery's timestamp may be from now before it is rejected as a replay
+pub const DEFAULT_TIMESTAMP_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Checks the `X-Gitee-Token` of incoming deliveries against the hook's password or signing key
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    auth: HookAuth,
+    tolerance: Option<Duration>,
+}
+
+impl WebhookVerifier {
+    /// Signed deliveries must carry a timestamp within [`DEFAULT_TIMESTAMP_TOLERANCE`] of now
+    pub fn new(auth: HookAuth) -> Self {
+        WebhookVerifier { auth, tolerance: Some(DEFAULT_TIMESTAMP_TOLERANCE) }
+    }
+
+    /// Reject signed deliveries whose timestamp is further than `tolerance` from now
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Accept signed deliveries of any age, e.g. when replaying recorded payloads.
+    /// A captured token and timestamp then stay valid forever.
+    pub fn without_tolerance(mut self) -> Self {
+        self.tolerance = None;
+        self
+    }
+
+    /// Verify a delivery from its `X-Gitee-Token` and `X-Gitee-Timestamp` header values
+    pub fn verify(&self, token: &str, timestamp: Option<&str>) -> Result<(), GiteeError> {
+        match &self.auth {
+            HookAuth::Password(password) => {
+                if constant_time_eq(token.as_bytes(), password.as_bytes()) {
+                    Ok(())
+                } else {
+                    Err(GiteeError::WebhookVerification("password does not match".to_string()))
+                }
+            }
+            HookAuth::SignKey(key) => {
+                let timestamp = timestamp
+                    .ok_or_else(|| GiteeError::WebhookVerification("missing X-Gitee-Timestamp".to_string()))?;
+                if let Some(tolerance) = self.tolerance {
+                    check_timestamp(timestamp, tolerance)?;
+                }
+
+                // Gitee URL-encodes the base64 signature in some deliveries
+                let token = percent_decode_str(token).decode_utf8_lossy();
+                let signature = base64::engine::general_purpose::STANDARD
+                    .decode(token.as_bytes())
+                    .map_err(|_| GiteeError::WebhookVerification("signature is not valid base64".to_string()))?;
+                signing_mac(key, timestamp)
+                    .verify_slice(&signature)
+                    .map_err(|_| GiteeError::WebhookVerification("signature does not match".to_string()))
+            }
+        }
+    }
+}
+
+/// The signature Gitee sends for a signing key and timestamp:
+/// base64 of HMAC-SHA256 over `"{timestamp}\n{key}"`, keyed with `key`
+pub fn sign(key: &str, timestamp: &str) -> String {
+    let digest = signing_mac(key, timestamp).finalize().into_bytes();
+    base64::engine::general_purpose::STANDARD.encode(digest)
+}
+
+fn signing_mac(key: &str, timestamp: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}\n{}", timestamp, key).as_bytes());
+    mac
+}
+
+fn check_timestamp(timestamp: &str, tolerance: Duration) -> Result<(), GiteeError> {
+    let sent = timestamp
+        .trim()
+        .parse::<u64>()
+        .map_err(|_| GiteeError::WebhookVerification(format!("invalid timestamp '{}'", timestamp)))?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+    if now.abs_diff(sent) > tolerance.as_millis() as u64 {
+        return Err(GiteeError::WebhookVerification("timestamp is outside the allowed tolerance".to_string()));
+    }
+    Ok(())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let path = format!("{}/tests/fixtures/webhooks/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn test_parse_recorded_payloads() {
+        match WebhookEvent::parse(Some("Push Hook"), &fixture("push")).unwrap() {
+            WebhookEvent::Push(push) => {
+                assert_eq!(push.branch(), Some("master"));
+                assert_eq!(push.commits[0].modified, vec!["src/main.rs"]);
+                assert_eq!(push.repository.full_name, "acme/demo");
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match WebhookEvent::parse(None, &fixture("tag_push")).unwrap() {
+            WebhookEvent::TagPush(push) => assert_eq!(push.tag(), Some("v1.0.0")),
+            other => panic!("unexpected event {:?}", other),
+        }
+        match WebhookEvent::parse(Some("Issue Hook"), &fixture("issue")).unwrap() {
+            WebhookEvent::Issue(event) => {
+                assert_eq!(event.action, "state_change");
+                assert_eq!(event.issue.number, "I8XYZ1");
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match WebhookEvent::parse(Some("Note Hook"), &fixture("note")).unwrap() {
+            WebhookEvent::Note(event) => {
+                assert_eq!(event.noteable_type, "PullRequest");
+                assert!(event.comment.is_review_comment());
+                assert_eq!(event.pull_request.map(|pr| pr.number), Some("42".to_string()));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match WebhookEvent::parse(Some("Merge Request Hook"), &fixture("merge_request")).unwrap() {
+            WebhookEvent::MergeRequest(event) => {
+                assert_eq!(event.pull_request.head.ref_name, "fix/config");
+                assert_eq!(event.merge_status.as_deref(), Some("can_be_merged"));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(matches!(
+            WebhookEvent::parse(Some("Wiki Hook"), b"{}").unwrap(),
+            WebhookEvent::Unknown { .. }
+        ));
+    }
+
+    #[test]
+    fn test_verify_signature_and_password() {
+        let expected = "3ygz/zXremRfJS9aQvqZSxvJt5GpdWQTX0mNDJKTZdY=";
+        assert_eq!(sign("gitee-secret", "1767225600000"), expected);
+
+        let verifier = WebhookVerifier::new(HookAuth::SignKey("gitee-secret".to_string())).without_tolerance();
+        assert!(verifier.verify(expected, Some("1767225600000")).is_ok());
+        assert!(verifier.verify("3ygz%2FzXremRfJS9aQvqZSxvJt5GpdWQTX0mNDJKTZdY%3D", Some("1767225600000")).is_ok());
+        assert!(verifier.verify(expected, Some("1767225600001")).is_err());
+        assert!(verifier.verify(expected, None).is_err());
+
+        // Old deliveries are rejected as replays by default, fresh ones pass
+        let verifier = WebhookVerifier::new(HookAuth::SignKey("gitee-secret".to_string()));
+        assert!(verifier.verify(expected, Some("1767225600000")).is_err());
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis().to_string();
+        assert!(verifier.verify(&sign("gitee-secret", &now), Some(&now)).is_ok());
+
+        let verifier = WebhookVerifier::new(HookAuth::Password("hunter2".to_string()));
+        assert!(verifier.verify("hunter2", None).is_ok());
+        assert!(matches!(verifier.verify("hunter3", None), Err(GiteeError::WebhookVerification(_))));
+    }
+
+    #[tokio::test]
+    async fn test_receiver_dispatches_to_matching_handlers() {
+        let pushes = Arc::new(AtomicUsize::new(0));
+        let all = Arc::new(AtomicUsize::new(0));
+        let receiver = WebhookReceiver::new()
+            .verifier(WebhookVerifier::new(HookAuth::Password("hunter2".to_string())))
+            .on_push({
+                let pushes = pushes.clone();
+                move |_| {
+                    let pushes = pushes.clone();
+                    async move {
+                        pushes.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            })
+            .on_event({
+                let all = all.clone();
+                move |_| {
+                    let all = all.clone();
+                    async move {
+                        all.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            });
+
+        receiver.receive(Some("Push Hook"), Some("hunter2"), None, &fixture("push")).await.unwrap();
+        receiver.receive(Some("Issue Hook"), Some("hunter2"), None, &fixture("issue")).await.unwrap();
+        assert!(receiver.receive(Some("Push Hook"), Some("wrong"), None, &fixture("push")).await.is_err());
+        assert!(receiver.receive(Some("Push Hook"), None, None, &fixture("push")).await.is_err());
+
+        assert_eq!(pushes.load(Ordering::SeqCst), 1);
+        assert_eq!(all.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::commits::GitActor;
+use crate::issues::Issue;
+use crate::pulls::{Comment, PullRequest};
+use crate::repos::Repository;
+use crate::users::User;
+
+/// A webhook delivery, parsed according to its event type
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    Push(Box<PushEvent>),
+    TagPush(Box<PushEvent>),
+    Issue(Box<IssueEvent>),
+    Note(Box<NoteEvent>),
+    MergeRequest(Box<MergeRequestEvent>),
+    /// An event this library has no model for; the raw payload is kept
+    Unknown { event: String, payload: Value },
+}
+
+/// Kind of a webhook event, as named in the `X-Gitee-Event` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEventKind {
+    Push,
+    TagPush,
+    Issue,
+    Note,
+    MergeRequest,
+}
+
+impl WebhookEventKind {
+    /// Parse an `X-Gitee-Event` header value (e.g. `Push Hook`) or a payload `hook_name` (e.g. `push_hooks`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim() {
+            "Push Hook" | "push_hooks" => Some(WebhookEventKind::Push),
+            "Tag Push Hook" | "tag_push_hooks" => Some(WebhookEventKind::TagPush),
+            "Issue Hook" | "issue_hooks" => Some(WebhookEventKind::Issue),
+            "Note Hook" | "note_hooks" => Some(WebhookEventKind::Note),
+            "Merge Request Hook" | "merge_request_hooks" => Some(WebhookEventKind::MergeRequest),
+            _ => None,
+        }
+    }
+}
+
+impl WebhookEvent {
+    /// The kind of this event, or `None` for unknown events
+    pub fn kind(&self) -> Option<WebhookEventKind> {
+        match self {
+            WebhookEvent::Push(_) => Some(WebhookEventKind::Push),
+            WebhookEvent::TagPush(_) => Some(WebhookEventKind::TagPush),
+            WebhookEvent::Issue(_) => Some(WebhookEventKind::Issue),
+            WebhookEvent::Note(_) => Some(WebhookEventKind::Note),
+            WebhookEvent::MergeRequest(_) => Some(WebhookEventKind::MergeRequest),
+            WebhookEvent::Unknown { .. } => None,
+        }
+    }
+}
+
+/// Payload of `Push Hook` and `Tag Push Hook` deliveries
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PushEvent {
+    #[serde(rename = "ref")]
+    pub ref_name: String, // e.g. "refs/heads/master" or "refs/tags/v1.0"
+    pub before: String,
+    pub after: String,
+    #[serde(default)]
+    pub created: bool,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub compare: Option<String>,
+    #[serde(default)]
+    pub commits: Vec<PushCommit>,
+    #[serde(default)]
+    pub head_commit: Option<PushCommit>,
+    #[serde(default)]
+    pub total_commits_count: i32,
+    pub repository: Repository,
+    #[serde(default)]
+    pub pusher: Option<User>,
+    #[serde(default)]
+    pub sender: Option<User>,
+}
+
+impl PushEvent {
+    /// Branch name, if a branch was pushed
+    pub fn branch(&self) -> Option<&str> {
+        self.ref_name.strip_prefix("refs/heads/")
+    }
+
+    /// Tag name, if a tag was pushed
+    pub fn tag(&self) -> Option<&str> {
+        self.ref_name.strip_prefix("refs/tags/")
+    }
+}
+
+/// A commit as listed in a push payload
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PushCommit {
+    pub id: String,
+    #[serde(default)]
+    pub tree_id: Option<String>,
+    pub message: String,
+    #[serde(default)]
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub author: Option<GitActor>,
+    #[serde(default)]
+    pub committer: Option<GitActor>,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+/// Payload of `Issue Hook` deliveries
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct IssueEvent {
+    pub action: String, // e.g. "open", "update", "state_change", "assign" or "delete"
+    #[serde(default)]
+    pub action_desc: Option<String>,
+    pub issue: Issue,
+    #[serde(default)]
+    pub repository: Option<Repository>, // Missing for issues outside a repository
+    #[serde(default)]
+    pub sender: Option<User>,
+    #[serde(default)]
+    pub updated_by: Option<User>,
+}
+
+/// Payload of `Note Hook` deliveries (comments)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NoteEvent {
+    pub action: String, // "comment" or "edited"
+    pub comment: Comment,
+    pub noteable_type: String, // "Issue", "PullRequest" or "Commit"
+    #[serde(default)]
+    pub repository: Option<Repository>,
+    #[serde(default)]
+    pub sender: Option<User>,
+    #[serde(default)]
+    pub issue: Option<Issue>, // Set when an issue was commented on
+    #[serde(default)]
+    pub pull_request: Option<PullRequest>, // Set when a pull request was commented on
+    #[serde(default)]
+    pub short_commit_id: Option<String>, // Set when a commit was commented on
+}
+
+/// Payload of `Merge Request Hook` deliveries
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MergeRequestEvent {
+    pub action: String, // e.g. "open", "update", "close", "merge", "approved" or "tested"
+    #[serde(default)]
+    pub action_desc: Option<String>,
+    pub pull_request: PullRequest,
+    #[serde(default)]
+    pub merge_status: Option<String>,
+    #[serde(default)]
+    pub repository: Option<Repository>,
+    #[serde(default)]
+    pub sender: Option<User>,
+    #[serde(default)]
+    pub updated_by: Option<User>,
+}
@@ -0,0 +1,144 @@
+use crate::error::GiteeError;
+use futures_util::future::BoxFuture;
+use std::future::Future;
+use std::sync::Arc;
+use super::{IssueEvent, MergeRequestEvent, NoteEvent, PushEvent, WebhookEvent, WebhookVerifier};
+
+type Handler = Arc<dyn Fn(&WebhookEvent) -> Option<BoxFuture<'static, ()>> + Send + Sync>;
+
+/// Verifies webhook deliveries and dispatches them to registered handlers.
+/// Handlers run one after another, in registration order, before the delivery is acknowledged.
+#[derive(Clone, Default)]
+pub struct WebhookReceiver {
+    verifier: Option<WebhookVerifier>,
+    handlers: Vec<Handler>,
+}
+
+impl WebhookReceiver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject deliveries that fail this verifier; without one every delivery is accepted
+    pub fn verifier(mut self, verifier: WebhookVerifier) -> Self {
+        self.verifier = Some(verifier);
+        self
+    }
+
+    /// Handle `Push Hook` deliveries
+    pub fn on_push<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(PushEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| match event {
+            WebhookEvent::Push(push) => Some(Box::pin(handler(push.as_ref().clone())) as BoxFuture<'static, ()>),
+            _ => None,
+        })
+    }
+
+    /// Handle `Tag Push Hook` deliveries
+    pub fn on_tag_push<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(PushEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| match event {
+            WebhookEvent::TagPush(push) => Some(Box::pin(handler(push.as_ref().clone())) as BoxFuture<'static, ()>),
+            _ => None,
+        })
+    }
+
+    /// Handle `Issue Hook` deliveries
+    pub fn on_issue<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(IssueEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| match event {
+            WebhookEvent::Issue(issue) => Some(Box::pin(handler(issue.as_ref().clone())) as BoxFuture<'static, ()>),
+            _ => None,
+        })
+    }
+
+    /// Handle `Note Hook` deliveries (comments)
+    pub fn on_note<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(NoteEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| match event {
+            WebhookEvent::Note(note) => Some(Box::pin(handler(note.as_ref().clone())) as BoxFuture<'static, ()>),
+            _ => None,
+        })
+    }
+
+    /// Handle `Merge Request Hook` deliveries
+    pub fn on_merge_request<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(MergeRequestEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| match event {
+            WebhookEvent::MergeRequest(mr) => Some(Box::pin(handler(mr.as_ref().clone())) as BoxFuture<'static, ()>),
+            _ => None,
+        })
+    }
+
+    /// Handle every delivery, including events without a typed model
+    pub fn on_event<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.register(move |event| Some(Box::pin(handler(event.clone())) as BoxFuture<'static, ()>))
+    }
+
+    fn register<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&WebhookEvent) -> Option<BoxFuture<'static, ()>> + Send + Sync + 'static,
+    {
+        self.handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// Verify and dispatch one delivery, given its `X-Gitee-Event`, `X-Gitee-Token` and
+    /// `X-Gitee-Timestamp` header values and the raw body
+    pub async fn receive(&self, event: Option<&str>, token: Option<&str>, timestamp: Option<&str>, body: &[u8]) -> Result<WebhookEvent, GiteeError> {
+        if let Some(verifier) = &self.verifier {
+            let token = token.ok_or_else(|| GiteeError::WebhookVerification("missing X-Gitee-Token".to_string()))?;
+            verifier.verify(token, timestamp)?;
+        }
+
+        let event = WebhookEvent::parse(event, body)?;
+        for handler in &self.handlers {
+            if let Some(task) = handler(&event) {
+                task.await;
+            }
+        }
+        Ok(event)
+    }
+}
+
+#[cfg(feature = "webhook-server")]
+impl WebhookReceiver {
+    /// An axum router accepting deliveries with `POST` at `path`. Verification failures
+    /// are answered with 401 and unparseable payloads with 400.
+    pub fn into_router(self, path: &str) -> axum::Router {
+        use axum::{body::Bytes, extract::State, http::{HeaderMap, StatusCode}, routing::post, Router};
+
+        async fn deliver(State(receiver): State<Arc<WebhookReceiver>>, headers: HeaderMap, body: Bytes) -> StatusCode {
+            let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+            match receiver
+                .receive(header("X-Gitee-Event"), header("X-Gitee-Token"), header("X-Gitee-Timestamp"), &body)
+                .await
+            {
+                Ok(_) => StatusCode::OK,
+                Err(GiteeError::WebhookVerification(_)) => StatusCode::UNAUTHORIZED,
+                Err(_) => StatusCode::BAD_REQUEST,
+            }
+        }
+
+        Router::new().route(path, post(deliver)).with_state(Arc::new(self))
+    }
+}